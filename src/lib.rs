@@ -0,0 +1,3580 @@
+//! Translate winapi-rs definitions into Zig.
+//!
+//! The entry points are [`convert_str`] and [`convert_file`], which return the
+//! generated Zig source along with any diagnostics for items that couldn't be
+//! translated.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{
+    braced, bracketed, parenthesized, token, Abi, Attribute, BinOp, Expr, ExprLit, Field, Fields,
+    FnArg, ForeignItem, GenericArgument, GenericParam, Ident, Item, ItemConst, ItemEnum, ItemFn,
+    ImplItem, ImplItemConst, ImplItemMethod, ItemForeignMod, ItemImpl, ItemMacro, ItemMod,
+    ItemStatic, ItemStruct,
+    ItemType, ItemUnion, ItemUse, Lit, LitFloat, LitInt, Member, Meta, MetaList, NestedMeta, Pat,
+    PathArguments, ReturnType, Stmt, Token, Type, TypePath, TypePtr, UseTree, VisPublic, Visibility,
+    UnOp, TypeArray, TypeBareFn, BareFnArg, FieldsNamed, ExprIf, ExprCast,
+};
+const USAGE: &str = "\
+Usage: winapi-zig [options] path/to/filename.rs...
+
+Options:
+    -o, --output PATH           Write to PATH, or into directory PATH for
+                                several inputs, keeping their paths from
+                                the directory containing them all
+    --default-lib NAME          Library for extern blocks without #[link]
+    --glob-as-usingnamespace    Translate glob imports to usingnamespace
+    --fmt                       Format the output with `zig fmt`
+    --list-unhandled            Only list the items that can't be translated
+    --type-map PATH             Read `RustType = \"ZigType\"` overrides from
+                                the TOML file PATH
+    --target TARGET             Translate for TARGET, such as i686-windows
+                                (default x86_64-windows)
+    --no-header                 Don't start the output with a generated-file
+                                banner
+    --link-lib-map PATH         Read `module = \"library\"` pairs choosing the
+                                library for extern blocks by module from the
+                                TOML file PATH
+    --module-dir NAME           Import submodules of the top-level module
+                                NAME from NAME/, like um/winuser.zig
+    --check-undeclared          Report types not declared or imported in the
+                                file
+    --verbose                   Log each top-level item as it's translated
+    --emit-build-zig            Also write a build.zig declaring each output
+                                as a module, into the -o directory or the
+                                one containing the inputs
+    --c-strings                 Translate string pointer typedefs like
+                                LPCSTR to sentinel-terminated pointers
+    --strict                    Exit with an error if any item isn't
+                                translated";
+
+#[derive(Debug)]
+pub enum Error {
+    IncorrectUsage,
+    ReadFile(io::Error),
+    WriteFile(io::Error),
+    ParseFile {
+        error: syn::Error,
+        /// The file being parsed, if the source came from one.
+        filepath: Option<PathBuf>,
+        source_code: String,
+    },
+    Unhandled(String),
+    Nyi,
+    /// A macro invocation whose body couldn't be parsed, with the macro's
+    /// name and the parse error.
+    Macro(&'static str, syn::Error),
+    /// A malformed `--type-map` file, with the line and what's wrong.
+    TypeMap(usize, String),
+    LinkLibMap(usize, String),
+    UnknownTarget(String),
+    FilesFailed(usize),
+    /// An output path that an earlier input in the same run was written to.
+    DuplicateOutput(PathBuf),
+    /// A module name that an earlier input in the same run already has.
+    DuplicateModule(String),
+    /// Items left untranslated under `--strict`, with their count.
+    Untranslated(usize),
+}
+
+/// A problem with a single item, which was skipped in the output or
+/// translated with a caveat.
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// The kind of item, such as `struct` or `macro`.
+    pub kind: &'static str,
+    /// The item's name, if it has one.
+    pub name: Option<String>,
+    pub message: String,
+    /// Whether the item, or part of it, was left out, rather than just
+    /// noted as possibly wrong.
+    pub skipped: bool,
+    /// The 1-based line where the item starts.
+    pub line: usize,
+    /// The 1-based column where the item starts.
+    pub column: usize,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)?;
+        if let Some(name) = &self.name {
+            write!(f, " {}", name)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// What became of one top-level item, as `--verbose` logs it.
+#[derive(Debug)]
+pub struct ItemReport {
+    /// The kind of item, as in `Diagnostic::kind`.
+    pub kind: &'static str,
+    pub name: Option<String>,
+    /// Whether it was translated, which it may have been only partly.
+    pub translated: bool,
+    /// The 1-based line where the item starts.
+    pub line: usize,
+    /// The 1-based column where the item starts.
+    pub column: usize,
+}
+
+impl Display for ItemReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let outcome = if self.translated { "translated" } else { "skipped" };
+        write!(f, "{}:{}: {} {}", self.line, self.column, outcome, self.kind)?;
+        if let Some(name) = &self.name {
+            write!(f, " {}", name)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of translating one Rust source file.
+#[derive(Debug)]
+pub struct Translation {
+    pub zig: String,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Each of the file's items, in order, with whether it was translated.
+    pub items: Vec<ItemReport>,
+    /// The files the Zig `@import`s for its `use`s, like `um/winuser.zig`.
+    pub imports: Vec<String>,
+}
+
+/// A translated file, as one of the modules declared by `build_zig`.
+#[derive(Debug)]
+pub struct BuildModule {
+    /// The module's name, like `winuser`.
+    pub name: String,
+    /// The Zig file, relative to the `build.zig`.
+    pub path: String,
+    /// The files it imports from the root of the crate, as in
+    /// `Translation::imports`.
+    pub imports: Vec<String>,
+}
+
+/// Parse a type map, the flat TOML table of `RustType = "ZigType"` pairs
+/// read by `--type-map`.
+pub fn parse_type_map(source: &str) -> Result<HashMap<String, String>, Error> {
+    parse_string_table(source, Error::TypeMap)
+}
+
+/// Parse a link library map, the flat TOML table of `module = "library"`
+/// pairs read by `--link-lib-map`.
+pub fn parse_link_lib_map(source: &str) -> Result<HashMap<String, String>, Error> {
+    parse_string_table(source, Error::LinkLibMap)
+}
+
+/// Parse a flat TOML table of strings, making errors with `make_error` from
+/// the line number and a message.
+///
+/// Only what the maps need is supported: bare or quoted keys, basic string
+/// values, and `#` comments.
+fn parse_string_table(
+    source: &str,
+    make_error: fn(usize, String) -> Error,
+) -> Result<HashMap<String, String>, Error> {
+    let mut map = HashMap::new();
+    for (i, line) in source.lines().enumerate() {
+        let error = |message: &str| make_error(i + 1, message.into());
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, rest) = if line.starts_with('"') {
+            parse_toml_str(line).ok_or_else(|| error("unterminated key"))?
+        } else {
+            let end = line
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(line.len());
+            (line[..end].to_string(), &line[end..])
+        };
+        if key.is_empty() {
+            return Err(error("expected a name"));
+        }
+        let rest = rest.trim_start().strip_prefix('=').ok_or_else(|| error("expected `=`"))?;
+        let rest = rest.trim_start();
+        if !rest.starts_with('"') {
+            return Err(error("expected a string value"));
+        }
+        let (value, rest) = parse_toml_str(rest).ok_or_else(|| error("unterminated value"))?;
+        let rest = rest.trim_start();
+        if !(rest.is_empty() || rest.starts_with('#')) {
+            return Err(error("unexpected text after value"));
+        }
+        map.insert(key, value);
+    }
+    Ok(map)
+}
+
+/// Split a TOML basic string off the start of `s`, which begins with `"`.
+fn parse_toml_str(s: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = s.char_indices().skip(1);
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &s[i + 1..])),
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// The Windows target being translated for, which decides `#[cfg]`s and the
+/// meaning of the `"system"` ABI.
+#[derive(Clone, Debug)]
+pub struct Target {
+    /// The `target_arch`, such as `x86_64` or `x86`.
+    pub arch: &'static str,
+    /// The pointer width in bits.
+    pub pointer_width: u32,
+}
+
+impl Target {
+    /// Parse a target like `x86_64-windows` or `i686-pc-windows-msvc`.
+    ///
+    /// Only the architecture is looked at beyond checking for `windows`,
+    /// since that's all winapi's `cfg`s depend on.
+    pub fn parse(name: &str) -> Option<Target> {
+        let mut parts = name.split('-');
+        let (arch, pointer_width) = match parts.next()? {
+            "x86_64" | "amd64" => ("x86_64", 64),
+            "x86" | "i386" | "i586" | "i686" => ("x86", 32),
+            "aarch64" | "arm64" => ("aarch64", 64),
+            "arm" | "thumbv7a" => ("arm", 32),
+            _ => return None,
+        };
+        if !parts.any(|part| part == "windows") {
+            return None;
+        }
+        Some(Target {
+            arch,
+            pointer_width,
+        })
+    }
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Target {
+            arch: "x86_64",
+            pointer_width: 64,
+        }
+    }
+}
+
+impl Translation {
+    /// The number of items, or parts of items, left out of the output.
+    pub fn untranslated(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.skipped).count()
+    }
+
+    /// Summarize the untranslated items, one line per kind of item with its
+    /// count and names, like `macro (2): FOO!, BAR!`.
+    pub fn unhandled_summary(&self) -> String {
+        let mut by_kind: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for d in self.diagnostics.iter().filter(|d| d.skipped) {
+            let name = d.name.as_deref().unwrap_or("<unnamed>");
+            by_kind.entry(d.kind).or_default().push(name);
+        }
+        by_kind
+            .iter()
+            .map(|(kind, names)| format!("{} ({}): {}\n", kind, names.len(), names.join(", ")))
+            .collect()
+    }
+}
+
+/// Settings for a translation.
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// The library for `extern` blocks without a `#[link(name = "...")]`.
+    pub default_lib: String,
+    /// Translate `use foo::*` into `usingnamespace foo;`.
+    pub glob_as_usingnamespace: bool,
+    pub target: Target,
+    /// Rust type names to translate to the given Zig types, overriding the
+    /// built-in rules.
+    pub type_map: HashMap<String, String>,
+    /// Top-level modules, like winapi's `um` and `shared`, whose submodules
+    /// are imported from files in a directory of that name rather than
+    /// through a file for the top-level module itself.
+    pub module_dirs: BTreeSet<String>,
+    /// The library for `extern` blocks without a `#[link(name = "...")]`,
+    /// by the name of the module being translated, which takes precedence
+    /// over `default_lib`.
+    pub link_libs: HashMap<String, String>,
+    /// Report types that are neither declared nor imported in the file.
+    pub check_undeclared: bool,
+    /// Translate type aliases of pointers to `c_char` or `wchar_t`, like
+    /// `LPCSTR`, into null-terminated many-item pointers.
+    pub c_strings: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            default_lib: "user32".into(),
+            glob_as_usingnamespace: false,
+            target: Target::default(),
+            type_map: HashMap::new(),
+            module_dirs: BTreeSet::new(),
+            link_libs: HashMap::new(),
+            check_undeclared: false,
+            c_strings: false,
+        }
+    }
+}
+
+/// Declarations written one after another, with the blank lines between
+/// them.
+///
+/// Runs of one-line declarations, like a block of constants, stay together,
+/// but a declaration spanning several lines is set apart by a blank line on
+/// each side.
+#[derive(Default)]
+struct Decls {
+    out: Vec<u8>,
+    /// Whether the previous declaration spanned several lines, if there was one.
+    last_multiline: Option<bool>,
+}
+
+impl Decls {
+    /// Add a declaration's complete text, which may be empty for an item
+    /// that translates to nothing.
+    fn push(&mut self, decl: &[u8]) {
+        if decl.is_empty() {
+            return;
+        }
+        let multiline = decl.iter().filter(|&&b| b == b'\n').count() > 1;
+        if let Some(last_multiline) = self.last_multiline {
+            if last_multiline || multiline {
+                self.out.push(b'\n');
+            }
+        }
+        self.out.extend_from_slice(decl);
+        self.last_multiline = Some(multiline);
+    }
+
+    fn into_inner(self) -> Vec<u8> {
+        self.out
+    }
+}
+
+struct Cx {
+    options: Options,
+    /// The module being translated, named after its file.
+    module: Option<String>,
+    /// Files to `@import`, by the name they're bound to, emitted as a sorted
+    /// block at the top.
+    toplevel_imports: BTreeMap<String, String>,
+    /// The type `Self` refers to, inside an `impl`.
+    self_ty: Option<String>,
+    /// How many inline modules deep the current item is.
+    mod_depth: usize,
+    /// Enums named by `DEFINE_ENUM_FLAG_OPERATORS!`, whose values combine.
+    flag_enums: HashSet<String>,
+    /// Struct sizes asserted by `const_assert!`, by struct name.
+    size_asserts: HashMap<String, String>,
+    /// Tuple structs from `STRUCT!`, by name.
+    tuple_structs: HashMap<String, TupleStruct>,
+    /// The parameters of the function whose body is being translated, with
+    /// the names of their types, or `None` in a constant expression.
+    fn_params: RefCell<Option<HashMap<String, String>>>,
+    /// The Zig type of the number being initialized, which the integer
+    /// literals in its initializer take, outside any cast.
+    init_ty: RefCell<Option<String>>,
+    /// The names of the declared types of the file's constants, by name.
+    const_types: HashMap<String, String>,
+    /// Whether each type alias or handle declared in the file is a pointer
+    /// rather than a number, by name, for the ones that are either.
+    typedef_ptrs: HashMap<String, bool>,
+    /// The associated constants of the structs and unions declared alongside
+    /// the items being translated, by type name, as declarations to go
+    /// inside the type.
+    assoc_consts: HashMap<String, Vec<u8>>,
+    /// Behind a `RefCell` so that lossy translations deep in `ty_to_zig`
+    /// can be noted too.
+    diagnostics: RefCell<Vec<Diagnostic>>,
+    /// The Zig names of the types referred to by name, and where.
+    type_refs: RefCell<Vec<(String, Span)>>,
+    /// Whether there's a glob import, which could bring in any name.
+    glob_import: bool,
+    /// What became of each top-level item so far.
+    items: Vec<ItemReport>,
+}
+
+impl Cx {
+    /// Record a diagnostic for a part of an item that's left untranslated.
+    fn diagnose(&self, kind: &'static str, name: Option<String>, message: &str, span: Span) {
+        self.record(kind, name, message, span, true);
+    }
+
+    /// Record a diagnostic for a translation that may be wrong.
+    fn note(&self, kind: &'static str, name: Option<String>, message: &str, span: Span) {
+        self.record(kind, name, message, span, false);
+    }
+
+    fn record(
+        &self,
+        kind: &'static str,
+        name: Option<String>,
+        message: &str,
+        span: Span,
+        skipped: bool,
+    ) {
+        let start = span.start();
+        self.diagnostics.borrow_mut().push(Diagnostic {
+            kind,
+            name,
+            message: message.into(),
+            skipped,
+            line: start.line,
+            column: start.column + 1,
+        });
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::Error::*;
+
+        match self {
+            IncorrectUsage => write!(f, "{}", USAGE),
+            ReadFile(error) => write!(f, "Unable to read file: {}", error),
+            WriteFile(error) => write!(f, "Unable to write file: {}", error),
+            ParseFile {
+                error,
+                filepath,
+                source_code,
+            } => {
+                let start = error.span().start();
+                writeln!(f, "Unable to parse file: {}", error)?;
+                let gutter = " ".repeat(start.line.to_string().len());
+                write!(f, "{}--> ", gutter)?;
+                if let Some(filepath) = filepath {
+                    write!(f, "{}:", filepath.display())?;
+                }
+                writeln!(f, "{}:{}", start.line, start.column + 1)?;
+                // Lines are 1-based; line 0 means the span has no location.
+                let line = start.line.checked_sub(1).and_then(|i| source_code.lines().nth(i));
+                if let Some(line) = line {
+                    writeln!(f, "{} |", gutter)?;
+                    writeln!(f, "{} | {}", start.line, line)?;
+                    write!(f, "{} | {}^", gutter, " ".repeat(start.column))?;
+                }
+                Ok(())
+            }
+            Unhandled(item_name) => write!(f, "Unhandled item {}", item_name),
+            Nyi => write!(f, "Not yet implemented"),
+            Macro(name, error) => write!(f, "Malformed {}! body: {}", name, error),
+            TypeMap(line, message) => write!(f, "Invalid type map line {}: {}", line, message),
+            LinkLibMap(line, message) => {
+                write!(f, "Invalid link library map line {}: {}", line, message)
+            }
+            UnknownTarget(name) => {
+                write!(f, "Unknown target {}, expected one like x86_64-windows", name)
+            }
+            FilesFailed(n) => write!(f, "{} file(s) failed to translate", n),
+            DuplicateOutput(path) => {
+                write!(f, "Output {} was already written from another input", path.display())
+            }
+            DuplicateModule(name) => write!(f, "Module {} is already another input", name),
+            Untranslated(n) => write!(f, "{} item(s) not translated in total", n),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::WriteFile(error)
+    }
+}
+
+fn path_as_single_ident(path: &syn::Path) -> Option<String> {
+    if path.segments.len() == 1 {
+        let seg = &path.segments[0];
+        if seg.arguments == PathArguments::None {
+            return Some(seg.ident.to_string());
+        }
+    }
+    None
+}
+
+/// Convert a multi-segment path like `minwindef::MAX_PATH` into its segments.
+fn path_as_use_path(path: &syn::Path) -> Option<UsePath> {
+    path.segments
+        .iter()
+        .map(|seg| match seg.arguments {
+            PathArguments::None => Some(seg.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn path_equals(path: &syn::Path, id: &str) -> bool {
+    if path.segments.len() == 1 {
+        let seg = &path.segments[0];
+        if seg.arguments == PathArguments::None {
+            return seg.ident == id;
+        }
+    }
+    false
+}
+
+/// Map the types in winapi's `ctypes` module to Zig primitives.
+///
+/// Zig has its own `c_short` through `c_ulonglong`, which track the target's
+/// C ABI, so those keep their names. Zig doesn't have c char or float types,
+/// so those (and the fixed-width `__intN` types) become sized primitives.
+fn ctype_to_zig(ident: &str) -> Option<&'static str> {
+    let zig = match ident {
+        "c_void" => "anyopaque",
+        "c_char" | "c_schar" => "i8",
+        "c_uchar" => "u8",
+        "c_short" => "c_short",
+        "c_ushort" => "c_ushort",
+        "c_int" => "c_int",
+        "c_uint" => "c_uint",
+        "c_long" => "c_long",
+        "c_ulong" => "c_ulong",
+        "c_longlong" => "c_longlong",
+        "c_ulonglong" => "c_ulonglong",
+        "c_float" => "f32",
+        "c_double" => "f64",
+        "wchar_t" => "u16",
+        "__int8" => "i8",
+        "__uint8" => "u8",
+        "__int16" => "i16",
+        "__uint16" => "u16",
+        "__int32" => "i32",
+        "__uint32" => "u32",
+        "__int64" => "i64",
+        "__uint64" => "u64",
+        _ => return None,
+    };
+    Some(zig)
+}
+
+/// The signedness and width of winapi's integer typedefs, from
+/// `shared::basetsd`, `minwindef`, `ntdef` and `windef`, so that casts to
+/// them are known even where they're imported.
+fn winapi_int_type(name: &str, pointer_width: u32) -> Option<(bool, u32)> {
+    Some(match name {
+        "BYTE" | "UCHAR" | "BOOLEAN" | "UINT8" => (false, 8),
+        "CHAR" | "CCHAR" | "INT8" => (true, 8),
+        "WORD" | "USHORT" | "WCHAR" | "ATOM" | "LANGID" | "UINT16" => (false, 16),
+        "SHORT" | "INT16" => (true, 16),
+        "DWORD" | "UINT" | "ULONG" | "UINT32" | "ULONG32" | "DWORD32" | "COLORREF" | "LCID"
+        | "ACCESS_MASK" => (false, 32),
+        "INT" | "LONG" | "BOOL" | "HRESULT" | "NTSTATUS" | "INT32" | "LONG32" => (true, 32),
+        "DWORD64" | "DWORDLONG" | "ULONGLONG" | "UINT64" | "ULONG64" => (false, 64),
+        "LONGLONG" | "INT64" | "LONG64" => (true, 64),
+        "UINT_PTR" | "ULONG_PTR" | "DWORD_PTR" | "SIZE_T" | "WPARAM" => (false, pointer_width),
+        "INT_PTR" | "LONG_PTR" | "SSIZE_T" | "LPARAM" | "LRESULT" => (true, pointer_width),
+        _ => return None,
+    })
+}
+
+/// Whether `name` is one of winapi's pointer or handle typedefs.
+fn is_winapi_ptr_typedef(name: &str) -> bool {
+    matches!(
+        name,
+        "HANDLE"
+            | "PHANDLE"
+            | "LPHANDLE"
+            | "PVOID"
+            | "LPVOID"
+            | "LPCVOID"
+            | "HACCEL"
+            | "HBITMAP"
+            | "HBRUSH"
+            | "HCOLORSPACE"
+            | "HCURSOR"
+            | "HDC"
+            | "HDESK"
+            | "HDWP"
+            | "HENHMETAFILE"
+            | "HFONT"
+            | "HGDIOBJ"
+            | "HGLOBAL"
+            | "HGLRC"
+            | "HHOOK"
+            | "HICON"
+            | "HINSTANCE"
+            | "HKEY"
+            | "HKL"
+            | "HLOCAL"
+            | "HMENU"
+            | "HMETAFILE"
+            | "HMODULE"
+            | "HMONITOR"
+            | "HPALETTE"
+            | "HPEN"
+            | "HRGN"
+            | "HRSRC"
+            | "HWINSTA"
+            | "HWND"
+            | "LPSTR"
+            | "LPCSTR"
+            | "LPWSTR"
+            | "LPCWSTR"
+            | "PSTR"
+            | "PCSTR"
+            | "PWSTR"
+            | "PCWSTR"
+            | "LPBYTE"
+            | "PBYTE"
+            | "LPDWORD"
+            | "PDWORD"
+            | "LPWORD"
+    )
+}
+
+fn ty_to_zig(ty: &Type, cx: &Cx) -> Result<String, Error> {
+    match ty {
+        Type::Path(TypePath { path, .. }) if path.segments.len() == 1 => {
+            let seg = &path.segments[0];
+            if seg.arguments == PathArguments::None {
+                let ident = seg.ident.to_string();
+                if let Some(zig) = cx.options.type_map.get(&ident) {
+                    return Ok(zig.clone());
+                }
+                match ident.as_str() {
+                    // Rust's `bool` is a byte, matching Zig's. Win32's `BOOL`
+                    // is a four-byte int, so it's resolved here rather than
+                    // risk being treated like `bool`.
+                    "bool" => return Ok("bool".into()),
+                    "BOOL" => return Ok("c_int".into()),
+                    "Self" if cx.self_ty.is_some() => return Ok(cx.self_ty.clone().unwrap()),
+                    _ => (),
+                }
+                // Rust primitives like `u16` and `usize` are spelled the same in
+                // Zig, but other names may need quoting.
+                if let Some(zig) = ctype_to_zig(&ident) {
+                    return Ok(zig.into());
+                }
+                if is_rust_primitive(&ident) {
+                    return Ok(ident);
+                }
+                let zig = zig_ident(&ident);
+                cx.type_refs.borrow_mut().push((zig.clone(), seg.ident.span()));
+                return Ok(zig);
+            }
+            if let Some(arg) = single_type_arg(&seg.arguments) {
+                return generic_ty_to_zig(&seg.ident, arg, cx);
+            }
+        }
+        Type::Ptr(p) => return ptr_to_zig(p, cx),
+        // References are never null, so unlike raw pointers they aren't
+        // optional. A reference to a slice is a Zig slice.
+        Type::Reference(r) => {
+            let mut_str = if r.mutability.is_some() { "" } else { "const " };
+            if let Type::Slice(s) = &*r.elem {
+                return Ok(format!("[]{}{}", mut_str, ty_to_zig(&s.elem, cx)?));
+            }
+            return Ok(format!("*{}{}", mut_str, ty_to_zig(&r.elem, cx)?));
+        }
+        Type::Slice(s) => return Ok(format!("[]{}", ty_to_zig(&s.elem, cx)?)),
+        Type::Array(a) => {
+            // The length is a constant expression, which can refer to
+            // other constants.
+            let len = expr_to_zig(&a.len, cx)
+                .map_err(|_| Error::Unhandled("array length".into()))?;
+            return Ok(format!("[{}]{}", len, ty_to_zig(&a.elem, cx)?));
+        }
+        Type::BareFn(f) => return bare_fn_ty_to_zig(f, cx),
+        // Only ever a return type, for functions like `ExitProcess`.
+        Type::Never(_) => return Ok("noreturn".into()),
+        // Grouping, whether written or left by a macro expansion, means
+        // nothing once the type is spelled out in Zig.
+        Type::Paren(p) => return ty_to_zig(&p.elem, cx),
+        Type::Group(g) => return ty_to_zig(&g.elem, cx),
+        // `()` is `void` whether written out or implied by a missing return
+        // type, and longer tuples become Zig's tuple structs.
+        Type::Tuple(t) if t.elems.is_empty() => return Ok("void".into()),
+        Type::Tuple(t) => {
+            let elems = t.elems.iter().map(|e| ty_to_zig(e, cx)).collect::<Result<Vec<_>, _>>()?;
+            return Ok(format!("struct {{ {} }}", elems.join(", ")));
+        }
+        _ => (),
+    }
+    Err(Error::Nyi)
+}
+
+/// The argument of a path segment like `Wrapper<T>`, if it has exactly one
+/// type argument.
+fn single_type_arg(args: &PathArguments) -> Option<&Type> {
+    if let PathArguments::AngleBracketed(a) = args {
+        if a.args.len() == 1 {
+            if let GenericArgument::Type(ty) = &a.args[0] {
+                return Some(ty);
+            }
+        }
+    }
+    None
+}
+
+/// Translate a wrapper type with a single type argument.
+///
+/// `Box` and `Vec` have no FFI meaning, so they're translated on a best-effort
+/// basis, as a pointer and a slice that Zig code doesn't own, and noted.
+fn generic_ty_to_zig(ident: &Ident, arg: &Type, cx: &Cx) -> Result<String, Error> {
+    match ident.to_string().as_str() {
+        // Wrappers for union members and bitfield storage are laid out like
+        // what they wrap. An `extern union` can hold the member directly.
+        "UnionField" | "__BindgenBitfieldUnit" => ty_to_zig(arg, cx),
+        // `Option` only has the layout of a C pointer around a pointer, where
+        // `None` is null. Raw and fn pointers already translate as optional.
+        "Option" => match arg {
+            Type::Ptr(_) | Type::BareFn(_) => ty_to_zig(arg, cx),
+            Type::Reference(_) => Ok(format!("?{}", ty_to_zig(arg, cx)?)),
+            Type::Path(TypePath { path, .. })
+                if path.segments.len() == 1 && path.segments[0].ident == "NonNull" =>
+            {
+                Ok(format!("?{}", ty_to_zig(arg, cx)?))
+            }
+            _ => Err(Error::Unhandled("Option of a non-pointer type".into())),
+        },
+        // `NonNull` is a `*mut` that's never null, so not optional, though
+        // a raw pointer it points to still may be.
+        "NonNull" => Ok(format!("*{}", ty_to_zig(arg, cx)?)),
+        "Box" => {
+            let message = "Translated lossily as a non-owning pointer";
+            cx.note("type", Some("Box".into()), message, ident.span());
+            Ok(format!("*{}", ty_to_zig(arg, cx)?))
+        }
+        "Vec" => {
+            let message = "Translated lossily as a non-owning slice";
+            cx.note("type", Some("Vec".into()), message, ident.span());
+            Ok(format!("[]{}", ty_to_zig(arg, cx)?))
+        }
+        _ => Err(Error::Unhandled(format!("generic type {}<...>", ident))),
+    }
+}
+
+/// Translate a raw pointer, which is optional, as C pointers may be null.
+///
+/// That goes for every level: the pointee of a `*mut *mut T` is a raw
+/// pointer too, and out-parameters like COM's `ppvObject` are routinely
+/// set to null through it. A pointer to a slice is used as a pointer to its first element, which is
+/// a many-item pointer in Zig. A pointer to an array points to the whole
+/// array, with `const` on the pointer as Zig puts it: `*const [u8; 8]` is
+/// `?*const [8]u8`.
+fn ptr_to_zig(p: &TypePtr, cx: &Cx) -> Result<String, Error> {
+    let mut_str = if p.const_token.is_some() {
+        "const "
+    } else {
+        ""
+    };
+    let (ptr_str, elem) = match &*p.elem {
+        Type::Ptr(inner) => ("*", ptr_to_zig(inner, cx)?),
+        Type::Slice(s) => ("[*]", ty_to_zig(&s.elem, cx)?),
+        elem => ("*", ty_to_zig(elem, cx)?),
+    };
+    Ok(format!("?{}{}{}", ptr_str, mut_str, elem))
+}
+
+fn abi_to_callconv(abi: Option<&Abi>, cx: &Cx) -> Result<&'static str, Error> {
+    // A missing ABI means the Rust ABI, which has no Zig equivalent.
+    let abi = abi.ok_or(Error::Nyi)?;
+    // `extern fn` with no string is the C ABI.
+    let name = abi.name.as_ref().map(|n| n.value()).unwrap_or_else(|| "C".into());
+    abi_name_to_callconv(&name, cx)
+}
+
+/// Map an `extern` ABI string to a Zig calling convention.
+///
+/// `"system"` is stdcall on 32-bit Windows, but Zig rejects `.Stdcall` on
+/// x86_64, where the Windows ABI is just the C one.
+fn abi_name_to_callconv(name: &str, cx: &Cx) -> Result<&'static str, Error> {
+    match name {
+        "system" if cx.options.target.arch == "x86" => Ok(".Stdcall"),
+        "system" => Ok(".C"),
+        "stdcall" => Ok(".Stdcall"),
+        "C" | "cdecl" => Ok(".C"),
+        "fastcall" => Ok(".Fastcall"),
+        "thiscall" => Ok(".Thiscall"),
+        _ => Err(Error::Unhandled(format!("abi \"{}\"", name))),
+    }
+}
+
+/// Translate function pointer parameters into a list of Zig parameters.
+fn bare_fn_args_to_zig<'a>(
+    inputs: impl IntoIterator<Item = &'a BareFnArg>,
+    cx: &Cx,
+) -> Result<Vec<String>, Error> {
+    let mut args = Vec::new();
+    for arg in inputs {
+        let ty = ty_to_zig(&arg.ty, cx)?;
+        match &arg.name {
+            Some((ident, _)) => args.push(format!("{}: {}", zig_ident(&ident.to_string()), ty)),
+            None => args.push(ty),
+        }
+    }
+    Ok(args)
+}
+
+/// Translate a function pointer type, such as a callback typedef.
+fn bare_fn_ty_to_zig(f: &TypeBareFn, cx: &Cx) -> Result<String, Error> {
+    let args = bare_fn_args_to_zig(&f.inputs, cx)?;
+    let ret = ret_ty_to_zig(&f.output, cx)?;
+    let callconv = abi_to_callconv(f.abi.as_ref(), cx)?;
+    Ok(format!("?*const fn({}) callconv({}) {}", args.join(", "), callconv, ret))
+}
+
+/// Translate a return type like any other type: typedefs such as `HWND` are
+/// kept by name, and raw pointers are optional, since Win32 functions signal
+/// failure by returning null.
+fn ret_ty_to_zig(r: &ReturnType, cx: &Cx) -> Result<String, Error> {
+    match r {
+        ReturnType::Type(_, t) => ty_to_zig(t, cx),
+        ReturnType::Default => Ok("void".to_string()),
+    }
+}
+
+/// Zig only has `pub` or not, so anything visible outside its own module,
+/// like `pub(crate)` or `pub(super)`, is made `pub`. Only `pub(self)`, which
+/// is the same as no visibility, stays private.
+fn vis_to_zig(v: &Visibility) -> &str {
+    match v {
+        Visibility::Public(_) | Visibility::Crate(_) => "pub ",
+        Visibility::Restricted(r) if !r.path.is_ident("self") => "pub ",
+        _ => "",
+    }
+}
+
+/// Zig's keywords, and the primitive names that can't be redeclared.
+const ZIG_RESERVED: &[&str] = &[
+    "addrspace", "align", "allowzero", "and", "anyframe", "anytype", "asm", "async", "await",
+    "break", "callconv", "catch", "comptime", "const", "continue", "defer", "else", "enum",
+    "errdefer", "error", "export", "extern", "fn", "for", "if", "inline", "linksection",
+    "noalias", "noinline", "nosuspend", "opaque", "or", "orelse", "packed", "pub", "resume",
+    "return", "struct", "suspend", "switch", "test", "threadlocal", "try", "union",
+    "unreachable", "usingnamespace", "var", "volatile", "while", "anyerror", "anyopaque", "bool",
+    "comptime_float", "comptime_int", "false", "noreturn", "null", "true", "type", "undefined",
+    "void",
+];
+
+/// Spell a Rust identifier for Zig, quoting it as `@"name"` where it would
+/// be a keyword or primitive. Raw identifiers like `r#type` lose their `r#`.
+fn zig_ident(name: &str) -> String {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    if ZIG_RESERVED.contains(&name) || is_zig_numeric_type(name) {
+        format!("@\"{}\"", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Whether `name` is one of Zig's numeric types, which include any width
+/// of integer, like `u7`, and the C types, like `c_long`.
+fn is_zig_numeric_type(name: &str) -> bool {
+    if let Some(bits) = name.strip_prefix('u').or_else(|| name.strip_prefix('i')) {
+        if !bits.is_empty() && bits.bytes().all(|b| b.is_ascii_digit()) && !bits.starts_with('0') {
+            return true;
+        }
+    }
+    matches!(name, "f16" | "f32" | "f64" | "f80" | "f128" | "isize" | "usize")
+        || matches!(name, "c_char" | "c_longdouble")
+        || ctype_to_zig(name).is_some_and(|zig| zig == name)
+}
+
+/// Whether `name` is one of Rust's numeric types, which Zig spells the same.
+fn is_rust_primitive(name: &str) -> bool {
+    matches!(
+        name,
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "isize" | "f32" | "f64"
+    )
+}
+
+/// Re-emit an integer literal without its Rust type suffix.
+///
+/// Zig uses the same `0x`/`0o`/`0b` radix prefixes and `_` separators, so the
+/// original spelling is kept otherwise. A float suffix, as in `2f32`, makes
+/// it a float literal.
+fn lit_int_to_zig(i: &LitInt) -> String {
+    let repr = i.to_string();
+    let digits = repr[..repr.len() - i.suffix().len()].trim_end_matches('_');
+    match i.suffix() {
+        "f32" | "f64" => format!("{}.0", digits),
+        _ => digits.to_string(),
+    }
+}
+
+/// Re-emit a float literal without its Rust type suffix.
+///
+/// As with integers, the suffix isn't kept as an `@as(f32, ...)`: an untyped
+/// Zig float coerces to either width where the constant is used. The one
+/// spelling Zig rejects is a trailing `.`, as in `1.`.
+fn lit_float_to_zig(f: &LitFloat) -> String {
+    let repr = f.to_string();
+    let digits = repr[..repr.len() - f.suffix().len()].trim_end_matches('_');
+    if digits.ends_with('.') {
+        format!("{}0", digits)
+    } else {
+        digits.to_string()
+    }
+}
+
+/// Append `c` to a Zig string or character literal, escaping as needed.
+fn push_escaped(out: &mut String, c: char) {
+    match c {
+        '\\' => out.push_str("\\\\"),
+        '"' => out.push_str("\\\""),
+        '\'' => out.push_str("\\'"),
+        '\n' => out.push_str("\\n"),
+        '\r' => out.push_str("\\r"),
+        '\t' => out.push_str("\\t"),
+        ' '..='~' => out.push(c),
+        c if (c as u32) < 0x80 => out.push_str(&format!("\\x{:02x}", c as u32)),
+        c => out.push_str(&format!("\\u{{{:x}}}", c as u32)),
+    }
+}
+
+fn str_to_zig(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        push_escaped(&mut out, c);
+    }
+    out.push('"');
+    out
+}
+
+/// A Zig character literal is the integer value of its code point.
+fn char_to_zig(c: char) -> String {
+    let mut out = String::from("'");
+    push_escaped(&mut out, c);
+    out.push('\'');
+    out
+}
+
+fn byte_str_to_zig(b: &[u8]) -> String {
+    let mut out = String::from("\"");
+    for &byte in b {
+        if byte < 0x80 {
+            push_escaped(&mut out, byte as char);
+        } else {
+            out.push_str(&format!("\\x{:02x}", byte));
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn is_zero_lit(e: &Expr) -> bool {
+    if let Expr::Lit(l) = e {
+        if let Lit::Int(i) = &l.lit {
+            return i.base10_digits() == "0";
+        }
+    }
+    false
+}
+
+/// Translate a constant expression, or fail if it has no Zig counterpart.
+fn expr_to_zig(e: &Expr, cx: &Cx) -> Result<String, Error> {
+    // A condition known for the target, like `cfg!(windows) && !cfg!(unix)`,
+    // is folded before an unknown part of it is translated.
+    if let Some(value) = const_bool(e, cx) {
+        return Ok(value.to_string());
+    }
+    match e {
+        Expr::Lit(l) => {
+            match &l.lit {
+                Lit::Int(i) => return Ok(lit_int_to_zig(i)),
+                Lit::Float(f) => return Ok(lit_float_to_zig(f)),
+                // Both Rust string forms are references to static data, as is
+                // a Zig string literal, so they need no further wrapping.
+                Lit::Str(s) => return Ok(str_to_zig(&s.value())),
+                Lit::ByteStr(b) => return Ok(byte_str_to_zig(&b.value())),
+                Lit::Char(c) => return Ok(char_to_zig(c.value())),
+                Lit::Bool(b) => return Ok(b.value.to_string()),
+                _ => (),
+            }
+        }
+        Expr::Unary(u) => {
+            let op = match u.op {
+                UnOp::Neg(_) => "-",
+                UnOp::Not(_) if is_bool_expr(&u.expr) => "!",
+                // `!` on an integer is bitwise not, which Zig spells `~`. It
+                // can't invert a `comptime_int`, which has no width, so the
+                // operand is given its type where that's known.
+                UnOp::Not(_) => match not_operand_ty(&u.expr, cx) {
+                    Some(ty) => {
+                        let operand = match &*u.expr {
+                            Expr::Paren(p) => &*p.expr,
+                            e => e,
+                        };
+                        return Ok(format!("~@as({}, {})", ty, expr_to_zig(operand, cx)?));
+                    }
+                    None => "~",
+                },
+                // Zig dereferences with a postfix `.*`, which binds tighter
+                // still, so only a path or a parenthesized operand is bare.
+                UnOp::Deref(_) => {
+                    let operand = expr_to_zig(&u.expr, cx)?;
+                    return Ok(match &*u.expr {
+                        Expr::Path(_) | Expr::Paren(_) => format!("{}.*", operand),
+                        _ => format!("({}).*", operand),
+                    });
+                }
+            };
+            return prefix_op_to_zig(op, &u.expr, cx);
+        }
+        // Taking the address of a constant gives a pointer to it in Zig too,
+        // and `&mut` is no different there.
+        Expr::Reference(r) => return prefix_op_to_zig("&", &r.expr, cx),
+        Expr::Cast(c) => {
+            // The operand has its own type, whatever the declared one.
+            let init_ty = cx.init_ty.replace(None);
+            let zig = cast_to_zig(c, cx);
+            cx.init_ty.replace(init_ty);
+            return zig;
+        }
+        Expr::Binary(b) => {
+            let op = match b.op {
+                BinOp::BitOr(_) => "|",
+                BinOp::BitAnd(_) => "&",
+                BinOp::BitXor(_) => "^",
+                BinOp::Shl(_) => "<<",
+                BinOp::Shr(_) => ">>",
+                BinOp::Add(_) => "+",
+                BinOp::Sub(_) => "-",
+                BinOp::Mul(_) => "*",
+                BinOp::Div(_) => "/",
+                BinOp::Rem(_) => "%",
+                BinOp::Eq(_) => "==",
+                BinOp::Ne(_) => "!=",
+                BinOp::Lt(_) => "<",
+                BinOp::Le(_) => "<=",
+                BinOp::Gt(_) => ">",
+                BinOp::Ge(_) => ">=",
+                BinOp::And(_) => "and",
+                BinOp::Or(_) => "or",
+                _ => return Err(Error::Unhandled("binary operator".into())),
+            };
+            // Zig puts `&`, `^` and `|` at the same precedence level, so
+            // nested binary expressions are grouped explicitly unless they
+            // are a left-associative chain of the same operator. Arithmetic
+            // is grouped the same way, for clarity more than need.
+            // The operands of `&&` and `||` are `bool`s.
+            let operand = match b.op {
+                BinOp::And(_) | BinOp::Or(_) => bool_expr_to_zig,
+                _ => expr_to_zig,
+            };
+            let left = match &*b.left {
+                Expr::Binary(l) if l.op != b.op => format!("({})", operand(&b.left, cx)?),
+                _ => operand(&b.left, cx)?,
+            };
+            let right = match &*b.right {
+                Expr::Binary(_) => format!("({})", operand(&b.right, cx)?),
+                _ => operand(&b.right, cx)?,
+            };
+            return Ok(format!("{} {} {}", left, op, right));
+        }
+        Expr::Path(p) => {
+            if let Some(ident) = path_as_single_ident(&p.path) {
+                return Ok(zig_ident(&ident));
+            }
+            if let Some(path) = path_as_use_path(&p.path) {
+                return Ok(use_path_to_zig(&path));
+            }
+        }
+        Expr::Macro(m) => {
+            if let Some((ty, arg)) = int_to_ptr_macro(&m.mac) {
+                return Ok(format!("@as({}, @ptrFromInt({}))", ty, expr_to_zig(&arg, cx)?));
+            }
+            // A `cfg!` known for the target was folded already.
+            if m.mac.path.is_ident("cfg") {
+                return Err(Error::Unhandled(format!("cfg!({})", m.mac.tokens)));
+            }
+        }
+        Expr::If(i) => return if_to_zig(i, cx),
+        // Explicit grouping is kept, and the binary arm doesn't add its own
+        // parentheses around it.
+        Expr::Paren(p) => return Ok(format!("({})", expr_to_zig(&p.expr, cx)?)),
+        // Without a declared type to give the element type, an array is an
+        // anonymous list literal, which coerces to the array it's used as.
+        Expr::Array(a) => {
+            let elems = a.elems.iter().map(|e| expr_to_zig(e, cx)).collect::<Result<Vec<_>, _>>()?;
+            return Ok(braced_list(".", &elems));
+        }
+        Expr::Call(c) => return call_to_zig(c, cx),
+        // Zig has methods too, so this may be right, but whether it can run
+        // at compile time depends on a body we can't see.
+        Expr::MethodCall(m) if m.turbofish.is_none() => {
+            let receiver = expr_to_zig(&m.receiver, cx)?;
+            let args = m.args.iter().map(|a| expr_to_zig(a, cx)).collect::<Result<Vec<_>, _>>()?;
+            let method = m.method.to_string();
+            let message = "Method call may not be evaluable at compile time in Zig";
+            cx.note("method call", Some(method.clone()), message, m.method.span());
+            return Ok(format!("{}.{}({})", receiver, zig_ident(&method), args.join(", ")));
+        }
+        // Zig has no struct update syntax, and the base's remaining fields
+        // can't be listed without knowing the struct's declaration.
+        Expr::Struct(st) if st.rest.is_some() => {
+            return Err(Error::Unhandled("struct literal with ..base".into()));
+        }
+        Expr::Struct(st) => {
+            let name = match path_as_use_path(&st.path) {
+                Some(path) => use_path_to_zig(&path),
+                None => return Err(Error::Unhandled("struct literal path".into())),
+            };
+            let mut fields = Vec::new();
+            for f in &st.fields {
+                match &f.member {
+                    Member::Named(ident) => {
+                        let name = zig_ident(&ident.to_string());
+                        fields.push(format!(".{} = {}", name, expr_to_zig(&f.expr, cx)?))
+                    }
+                    Member::Unnamed(_) => {
+                        return Err(Error::Unhandled("struct literal with numbered fields".into()))
+                    }
+                }
+            }
+            return Ok(braced_list(&name, &fields));
+        }
+        _ => (),
+    }
+    Err(Error::Unhandled("expression".into()))
+}
+
+/// Translate a cast to a number or pointer type.
+fn cast_to_zig(c: &ExprCast, cx: &Cx) -> Result<String, Error> {
+    let ty = ty_to_zig(&c.ty, cx)?;
+    let is_ptr = match is_ptr_type(&c.ty, &cx.typedef_ptrs, &cx.options) {
+        Some(is_ptr) => is_ptr,
+        None => {
+            let message = format!("cast to {}, which may be a pointer", ty);
+            return Err(Error::Unhandled(message));
+        }
+    };
+    if !is_ptr {
+        return runtime_cast_to_zig(&c.expr, &c.ty, &ty, cx);
+    }
+    // A raw pointer type is spelled out by the declaration, but a
+    // typedef's null keeps its type here.
+    if is_zero_lit(&c.expr) {
+        return Ok(match &*c.ty {
+            Type::Ptr(_) => "null".into(),
+            _ => format!("@as({}, null)", ty),
+        });
+    }
+    // `@ptrFromInt` takes a `usize`, so a negative address, like the
+    // `-1` of `INVALID_HANDLE_VALUE`, is reinterpreted as one.
+    let addr = match &*c.expr {
+        Expr::Unary(u) if matches!(u.op, UnOp::Neg(_)) => {
+            format!("@as(usize, @bitCast(@as(isize, {})))", expr_to_zig(&c.expr, cx)?)
+        }
+        e => expr_to_zig(e, cx)?,
+    };
+    Ok(format!("@as({}, @ptrFromInt({}))", ty, addr))
+}
+
+/// Translate a cast to the number type `ty`, which is `zig_ty` in Zig.
+///
+/// A Rust cast that narrows or changes the sign has to be said so in Zig,
+/// with `@truncate` or `@bitCast`, which need the operand's type. That's
+/// known from a literal's suffix, the file's constants and the function's
+/// parameters, and what's computed from them. A constant of unknown type is
+/// known at compile time at least, and `@as` checks that it fits.
+fn runtime_cast_to_zig(e: &Expr, ty: &Type, zig_ty: &str, cx: &Cx) -> Result<String, Error> {
+    let params = cx.fn_params.borrow();
+    let in_fn = params.is_some();
+    let types = params.as_ref().unwrap_or(&cx.const_types);
+    let from = expr_int_type(e, types).and_then(|from| int_type(&from, cx));
+    let to = match ty {
+        Type::Path(TypePath { qself: None, path }) => path_as_single_ident(path),
+        _ => None,
+    };
+    let ((from_signed, from), (to_signed, to)) =
+        match (from, to.as_deref().and_then(|to| int_type(to, cx))) {
+            (Some(from), Some(to)) => (from, to),
+            _ if !in_fn || matches!(e, Expr::Lit(_)) => {
+                return Ok(format!("@as({}, {})", zig_ty, expr_to_zig(e, cx)?));
+            }
+            _ => return Err(Error::Unhandled("cast of a value of unknown type".into())),
+        };
+    // Widening to a type that holds every value needs no more than `@as`.
+    if (from_signed, from) == (to_signed, to) || to > from && (to_signed || !from_signed) {
+        return Ok(format!("@as({}, {})", zig_ty, expr_to_zig(e, cx)?));
+    }
+    // The builtin's own parentheses group the operand.
+    let inner = match e {
+        Expr::Paren(p) => &*p.expr,
+        e => e,
+    };
+    let literal = match inner {
+        Expr::Unary(u) => matches!(&*u.expr, Expr::Lit(_)),
+        e => matches!(e, Expr::Lit(_)),
+    };
+    // A literal, or a constant declared without a type, is a
+    // `comptime_int`, which has no bits to reinterpret until it's given its
+    // Rust type.
+    let operand = if in_fn && !literal {
+        expr_to_zig(inner, cx)?
+    } else {
+        format!("@as({}, {})", int_zig(from_signed, from), expr_to_zig(inner, cx)?)
+    };
+    let converted = match to.cmp(&from) {
+        Ordering::Equal => format!("@bitCast({})", operand),
+        // Sign extension, then reinterpreting it.
+        Ordering::Greater => format!("@bitCast(@as({}, {}))", int_zig(true, to), operand),
+        Ordering::Less if from_signed == to_signed => format!("@truncate({})", operand),
+        Ordering::Less => {
+            let truncated = int_zig(from_signed, to);
+            format!("@bitCast(@as({}, @truncate({})))", truncated, operand)
+        }
+    };
+    Ok(format!("@as({}, {})", zig_ty, converted))
+}
+
+/// The Zig integer type with the given signedness and width.
+fn int_zig(signed: bool, width: u32) -> String {
+    format!("{}{}", if signed { 'i' } else { 'u' }, width)
+}
+
+/// The Zig type of the operand of a bitwise not: the type being
+/// initialized, or else the operand's own.
+fn not_operand_ty(e: &Expr, cx: &Cx) -> Option<String> {
+    if let Some(ty) = cx.init_ty.borrow().clone() {
+        return Some(ty);
+    }
+    let params = cx.fn_params.borrow();
+    let types = params.as_ref().unwrap_or(&cx.const_types);
+    let (signed, width) = int_type(&expr_int_type(e, types)?, cx)?;
+    Some(int_zig(signed, width))
+}
+
+/// The signedness and width of an integer type, for the Rust and C types
+/// and winapi typedefs whose Windows sizes are known, and what the type map
+/// maps to those.
+fn int_type(name: &str, cx: &Cx) -> Option<(bool, u32)> {
+    let ptr = cx.options.target.pointer_width;
+    // Zig spells the sized integers and C types like Rust does.
+    let name = match cx.options.type_map.get(name) {
+        Some(zig) => zig,
+        None => name,
+    };
+    Some(match name {
+        "u8" | "c_uchar" => (false, 8),
+        "u16" | "c_ushort" | "wchar_t" => (false, 16),
+        "u32" | "c_uint" | "c_ulong" => (false, 32),
+        "u64" | "c_ulonglong" => (false, 64),
+        "usize" => (false, ptr),
+        "i8" | "c_char" | "c_schar" => (true, 8),
+        "i16" | "c_short" => (true, 16),
+        "i32" | "c_int" | "c_long" => (true, 32),
+        "i64" | "c_longlong" => (true, 64),
+        "isize" => (true, ptr),
+        _ => return winapi_int_type(name, ptr),
+    })
+}
+
+/// The Rust name of an integer expression's type, as far as it follows from
+/// `params`, literal suffixes and casts.
+fn expr_int_type(e: &Expr, params: &HashMap<String, String>) -> Option<String> {
+    match e {
+        Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) if !i.suffix().is_empty() => {
+            Some(i.suffix().to_string())
+        }
+        Expr::Path(p) => params.get(&path_as_single_ident(&p.path)?).cloned(),
+        Expr::Paren(p) => expr_int_type(&p.expr, params),
+        Expr::Unary(u) if !matches!(u.op, UnOp::Deref(_)) => expr_int_type(&u.expr, params),
+        Expr::Cast(c) => match &*c.ty {
+            Type::Path(TypePath { qself: None, path }) => path_as_single_ident(path),
+            _ => None,
+        },
+        // A shift has the type of what's shifted.
+        Expr::Binary(b) if matches!(b.op, BinOp::Shl(_) | BinOp::Shr(_)) => {
+            expr_int_type(&b.left, params)
+        }
+        // An unsuffixed literal takes the type of the other operand.
+        Expr::Binary(b) => match (&*b.left, &*b.right) {
+            (Expr::Lit(_), other) | (other, Expr::Lit(_)) => expr_int_type(other, params),
+            (left, right) => {
+                let left = expr_int_type(left, params)?;
+                Some(left).filter(|left| expr_int_type(right, params).as_ref() == Some(left))
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Translate an `if` choosing between two values.
+///
+/// A condition known at translation time, such as a `cfg!` for the
+/// target, picks its branch here. Otherwise it becomes a Zig `if`, which is
+/// an expression too.
+fn if_to_zig(i: &ExprIf, cx: &Cx) -> Result<String, Error> {
+    let else_branch = match &i.else_branch {
+        Some((_, e)) => e,
+        None => return Err(Error::Unhandled("if without an else".into())),
+    };
+    let branch = |e: Option<&Expr>| match e {
+        Some(e) => expr_to_zig(e, cx),
+        None => Err(Error::Unhandled("if branch with statements".into())),
+    };
+    let then_zig = || branch(body_expr(&i.then_branch));
+    // The else branch is a block, or another `if` for `else if`.
+    let else_zig = || match &**else_branch {
+        Expr::Block(b) => branch(body_expr(&b.block)),
+        e => expr_to_zig(e, cx),
+    };
+    match const_bool(&i.cond, cx) {
+        Some(true) => then_zig(),
+        Some(false) => else_zig(),
+        None => {
+            let cond = bool_expr_to_zig(&i.cond, cx)?;
+            Ok(format!("if ({}) {} else {}", cond, then_zig()?, else_zig()?))
+        }
+    }
+}
+
+/// The value of a condition known at translation time: a `bool` literal or
+/// a `cfg!` known for the target, combined with `!`, `&&` and `||`.
+///
+/// As in `eval_cfg`, an unknown operand only matters if the known one doesn't
+/// already decide the result.
+fn const_bool(e: &Expr, cx: &Cx) -> Option<bool> {
+    match e {
+        Expr::Lit(ExprLit { lit: Lit::Bool(b), .. }) => Some(b.value),
+        Expr::Macro(m) if m.mac.path.is_ident("cfg") => {
+            syn::parse2(m.mac.tokens.clone()).ok().and_then(|m| eval_cfg(&m, cx))
+        }
+        Expr::Paren(p) => const_bool(&p.expr, cx),
+        Expr::Unary(u) if matches!(u.op, UnOp::Not(_)) => const_bool(&u.expr, cx).map(|b| !b),
+        Expr::Binary(b) => {
+            let (left, right) = (const_bool(&b.left, cx), const_bool(&b.right, cx));
+            match b.op {
+                BinOp::And(_) if left == Some(false) || right == Some(false) => Some(false),
+                BinOp::Or(_) if left == Some(true) || right == Some(true) => Some(true),
+                BinOp::And(_) | BinOp::Or(_) => left.and(right),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Translate an expression Rust requires to be a `bool`, like a condition,
+/// where `!` is logical not.
+fn bool_expr_to_zig(e: &Expr, cx: &Cx) -> Result<String, Error> {
+    match e {
+        Expr::Unary(u) if matches!(u.op, UnOp::Not(_)) && const_bool(e, cx).is_none() => {
+            prefix_op_to_zig("!", &u.expr, cx)
+        }
+        _ => expr_to_zig(e, cx),
+    }
+}
+
+/// Whether an expression is a `bool`, which Zig negates with `!` rather
+/// than the `~` for integers.
+fn is_bool_expr(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(ExprLit { lit: Lit::Bool(_), .. }) => true,
+        Expr::Macro(m) => m.mac.path.is_ident("cfg"),
+        Expr::Paren(p) => is_bool_expr(&p.expr),
+        Expr::Unary(u) => matches!(u.op, UnOp::Not(_)) && is_bool_expr(&u.expr),
+        Expr::Binary(b) => matches!(
+            b.op,
+            BinOp::Eq(_)
+                | BinOp::Ne(_)
+                | BinOp::Lt(_)
+                | BinOp::Le(_)
+                | BinOp::Gt(_)
+                | BinOp::Ge(_)
+                | BinOp::And(_)
+                | BinOp::Or(_)
+        ),
+        _ => false,
+    }
+}
+
+fn prefix_op_to_zig(op: &str, operand: &Expr, cx: &Cx) -> Result<String, Error> {
+    let zig = expr_to_zig(operand, cx)?;
+    // Unary operators bind tighter than anything except a literal or path.
+    Ok(match operand {
+        Expr::Lit(_) | Expr::Path(_) | Expr::Unary(_) | Expr::Reference(_) | Expr::Paren(_) => {
+            format!("{}{}", op, zig)
+        }
+        _ => format!("{}({})", op, zig),
+    })
+}
+
+/// Translate a call in a constant expression.
+///
+/// Rust's `size_of` and `align_of` become Zig builtins. A tuple struct's
+/// constructor becomes a struct literal, or for a transparent one, which is
+/// just its field's type, a coercion. Anything else is taken to be a
+/// function, which Zig evaluates at compile time in a constant.
+fn call_to_zig(c: &syn::ExprCall, cx: &Cx) -> Result<String, Error> {
+    let unhandled = || Error::Unhandled("call".into());
+    let path = match &*c.func {
+        Expr::Path(p) if p.qself.is_none() => &p.path,
+        _ => return Err(unhandled()),
+    };
+    let args = c.args.iter().map(|a| expr_to_zig(a, cx)).collect::<Result<Vec<_>, _>>()?;
+    let last = path.segments.last().ok_or_else(unhandled)?;
+    let builtin = match last.ident.to_string().as_str() {
+        "size_of" => Some("@sizeOf"),
+        "align_of" => Some("@alignOf"),
+        _ => None,
+    };
+    if let (Some(builtin), true) = (builtin, args.is_empty()) {
+        let ty = single_type_arg(&last.arguments).ok_or_else(unhandled)?;
+        return Ok(format!("{}({})", builtin, ty_to_zig(ty, cx)?));
+    }
+    let name = match path_as_single_ident(path) {
+        Some(name) => name,
+        None => match path_as_use_path(path) {
+            Some(path) => return Ok(format!("{}({})", use_path_to_zig(&path), args.join(", "))),
+            None => return Err(unhandled()),
+        },
+    };
+    match cx.tuple_structs.get(&name) {
+        Some(ctor) if ctor.transparent => match ctor.fields.as_slice() {
+            [pos] if args.len() > *pos => Ok(format!("@as({}, {})", zig_ident(&name), args[*pos])),
+            _ => Err(unhandled()),
+        },
+        Some(ctor) => {
+            let mut fields = Vec::new();
+            for &pos in &ctor.fields {
+                let arg = args.get(pos).ok_or_else(unhandled)?;
+                fields.push(format!(".@\"{}\" = {}", pos, arg));
+            }
+            Ok(braced_list(&zig_ident(&name), &fields))
+        }
+        None => Ok(format!("{}({})", zig_ident(&name), args.join(", "))),
+    }
+}
+
+/// Recognize the winapi macros that make a pointer from an integer, giving
+/// the pointer type they produce and the integer.
+///
+/// These are `MAKEINTRESOURCEA!`, `MAKEINTRESOURCEW!` and `MAKEINTRESOURCE!`
+/// for resource ids, and `MAKEINTATOM!` for atoms.
+fn int_to_ptr_macro(mac: &syn::Macro) -> Option<(&'static str, Expr)> {
+    let ty = match path_as_single_ident(&mac.path)?.as_str() {
+        "MAKEINTRESOURCEA" => "LPSTR",
+        "MAKEINTRESOURCEW" | "MAKEINTRESOURCE" | "MAKEINTATOM" => "LPWSTR",
+        _ => return None,
+    };
+    Some((ty, mac.parse_body().ok()?))
+}
+
+/// Format `prefix{ a, b }`, or `prefix{}` when empty, as `zig fmt` does.
+fn braced_list(prefix: &str, elems: &[String]) -> String {
+    if elems.is_empty() {
+        format!("{}{{}}", prefix)
+    } else {
+        format!("{}{{ {} }}", prefix, elems.join(", "))
+    }
+}
+
+/// Translate the initializer of a const or static declared as type `ty`.
+///
+/// Zig array literals spell out their element type, which only the
+/// declaration knows, so arrays are handled here, `[x; N]` becoming
+/// `[_]T{x} ** N`.
+fn init_to_zig(e: &Expr, ty: &Type, cx: &Cx) -> Result<String, Error> {
+    // A resource id is best given the declared type, often `LPCWSTR`,
+    // rather than the macro's own.
+    if let Expr::Macro(m) = e {
+        if let (Some((_, arg)), Ok(ty)) = (int_to_ptr_macro(&m.mac), ty_to_zig(ty, cx)) {
+            return Ok(format!("@as({}, @ptrFromInt({}))", ty, expr_to_zig(&arg, cx)?));
+        }
+    }
+    let arr = match ty {
+        Type::Array(arr) => arr,
+        Type::Path(TypePath { qself: None, path }) if path.is_ident("bool") => {
+            return bool_expr_to_zig(e, cx);
+        }
+        Type::Path(_) if is_ptr_type(ty, &cx.typedef_ptrs, &cx.options) == Some(false) => {
+            let init_ty = cx.init_ty.replace(Some(ty_to_zig(ty, cx)?));
+            let zig = expr_to_zig(e, cx);
+            cx.init_ty.replace(init_ty);
+            return zig;
+        }
+        _ => return expr_to_zig(e, cx),
+    };
+    let elem_ty = format!("[_]{}", ty_to_zig(&arr.elem, cx)?);
+    match e {
+        Expr::Array(a) => {
+            let elems = a.elems.iter().map(|e| init_to_zig(e, &arr.elem, cx));
+            Ok(braced_list(&elem_ty, &elems.collect::<Result<Vec<_>, _>>()?))
+        }
+        Expr::Repeat(r) => {
+            let elem = init_to_zig(&r.expr, &arr.elem, cx)?;
+            Ok(format!("{}{{{}}} ** {}", elem_ty, elem, expr_to_zig(&r.len, cx)?))
+        }
+        _ => expr_to_zig(e, cx),
+    }
+}
+
+/// Find the string value of a `#[name = "..."]` attribute.
+fn attr_str_value(attrs: &[Attribute], name: &str) -> Option<String> {
+    for attr in attrs {
+        if !path_equals(&attr.path, name) {
+            continue;
+        }
+        if let Ok(Meta::NameValue(nv)) = attr.parse_meta() {
+            if let Lit::Str(s) = &nv.lit {
+                return Some(s.value());
+            }
+        }
+    }
+    None
+}
+
+/// Find the library named by a `#[link(name = "...")]` attribute.
+fn link_lib(attrs: &[Attribute]) -> Option<String> {
+    for attr in attrs {
+        if !path_equals(&attr.path, "link") {
+            continue;
+        }
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            for nested in &list.nested {
+                if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
+                    if let (true, Lit::Str(s)) = (path_equals(&nv.path, "name"), &nv.lit) {
+                        return Some(s.value());
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Evaluate a `cfg` predicate for the target, or `None` if it can't be known.
+fn eval_cfg(meta: &Meta, cx: &Cx) -> Option<bool> {
+    match meta {
+        Meta::Path(p) if path_equals(p, "windows") => Some(true),
+        Meta::Path(p) if path_equals(p, "unix") => Some(false),
+        Meta::NameValue(nv) => {
+            let value = match &nv.lit {
+                Lit::Str(s) => s.value(),
+                _ => return None,
+            };
+            let key = path_as_single_ident(&nv.path)?;
+            match key.as_str() {
+                "target_pointer_width" => {
+                    Some(value == cx.options.target.pointer_width.to_string())
+                }
+                "target_arch" => Some(value == cx.options.target.arch),
+                "target_os" | "target_family" => Some(value == "windows"),
+                _ => None,
+            }
+        }
+        Meta::List(list) => {
+            let args: Vec<_> = list
+                .nested
+                .iter()
+                .map(|nested| match nested {
+                    NestedMeta::Meta(m) => eval_cfg(m, cx),
+                    NestedMeta::Lit(_) => None,
+                })
+                .collect();
+            let key = path_as_single_ident(&list.path)?;
+            // An unknown argument only matters if the known ones don't
+            // already decide the result.
+            match key.as_str() {
+                "not" if args.len() == 1 => args[0].map(|b| !b),
+                "all" if args.contains(&Some(false)) => Some(false),
+                "any" if args.contains(&Some(true)) => Some(true),
+                "all" | "any" if args.contains(&None) => None,
+                "all" => Some(true),
+                "any" => Some(false),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Determine whether we should keep this item, or field, variant or impl
+/// item, based on its `#[cfg]`s.
+///
+/// Zig can't conditionally declare a name, so predicates known for the
+/// target are resolved here. Any others are recorded in a comment and the
+/// item is kept.
+fn cfg_to_zig(
+    attrs: &[Attribute],
+    cx: &Cx,
+    indent: &str,
+    out: &mut dyn Write,
+) -> Result<bool, Error> {
+    for attr in attrs {
+        if !path_equals(&attr.path, "cfg") {
+            continue;
+        }
+        let meta = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested.into_iter().next(),
+            _ => None,
+        };
+        match meta {
+            Some(NestedMeta::Meta(m)) => match eval_cfg(&m, cx) {
+                Some(true) => (),
+                Some(false) => return Ok(false),
+                None => writeln!(out, "{}// #[cfg{}]", indent, attr.tokens)?,
+            },
+            _ => writeln!(out, "{}// #[cfg{}]", indent, attr.tokens)?,
+        }
+    }
+    Ok(true)
+}
+
+type UsePath = Vec<String>;
+
+/// The Zig expression referring to an item imported by `path`.
+fn use_path_to_zig(path: &[String]) -> String {
+    path.iter().map(|seg| zig_ident(seg)).collect::<Vec<_>>().join(".")
+}
+
+/// A single imported path, and the name it's bound to locally, which is
+/// `None` for a glob import of everything under `path`.
+struct UseItem {
+    path: UsePath,
+    name: Option<String>,
+}
+
+/// Expand a use tree into individual paths.
+fn expand_use_tree(u: &UseTree) -> Result<Vec<UseItem>, Error> {
+    fn expand_rec(u: &UseTree, prefix: &[String], b: &mut Vec<UseItem>) -> Result<(), Error> {
+        match u {
+            UseTree::Path(p) => {
+                let mut path = prefix.to_owned();
+                path.push(p.ident.to_string());
+                expand_rec(&p.tree, &path, b)?;
+            }
+            // `use foo::{self}` imports `foo` itself.
+            UseTree::Name(n) if n.ident == "self" && !prefix.is_empty() => {
+                let name = prefix.last().cloned();
+                b.push(UseItem { path: prefix.to_owned(), name });
+            }
+            UseTree::Name(n) => {
+                let mut path = prefix.to_owned();
+                path.push(n.ident.to_string());
+                let name = Some(n.ident.to_string());
+                b.push(UseItem { path, name });
+            }
+            UseTree::Rename(r) => {
+                let mut path = prefix.to_owned();
+                path.push(r.ident.to_string());
+                let name = Some(r.rename.to_string());
+                b.push(UseItem { path, name });
+            }
+            UseTree::Glob(_) => {
+                let path = prefix.to_owned();
+                b.push(UseItem { path, name: None });
+            }
+            UseTree::Group(g) => {
+                for tree in &g.items {
+                    expand_rec(tree, prefix, b)?;
+                }
+            }
+        }
+        Ok(())
+    }
+    let mut b = Vec::new();
+    expand_rec(u, &[], &mut b)?;
+    Ok(b)
+}
+
+/// Resolve a `crate::`, `self::` or `super::` prefix of a use path, also
+/// returning whether the rest starts from a name in scope in the Zig file.
+///
+/// winapi's paths are relative to the crate root, like `shared::minwindef`,
+/// and their first module becomes an import, so `crate::` (or a leading
+/// `::`) is the same as none. The rest of `self::` is in scope already, and
+/// so is the rest of `super::` in an inline module, since a Zig container
+/// sees its parents' declarations. Leaving the file with `super::` can't be
+/// resolved.
+fn resolve_use_path(mut path: UsePath, cx: &Cx) -> Result<(UsePath, bool), Error> {
+    match path.first().map(String::as_str) {
+        Some("crate") => {
+            path.remove(0);
+            Ok((path, false))
+        }
+        Some("self") => {
+            path.remove(0);
+            Ok((path, true))
+        }
+        Some("super") => {
+            let supers = path.iter().take_while(|seg| *seg == "super").count();
+            if supers > cx.mod_depth {
+                return Err(Error::Unhandled("use of super outside the file".into()));
+            }
+            path.drain(..supers);
+            Ok((path, true))
+        }
+        _ => Ok((path, false)),
+    }
+}
+
+/// Import the file a crate-relative path starts in, returning the path from
+/// the name the file is bound to.
+///
+/// That's the path's first module, `um` in `um::winuser::HWND`, unless it's
+/// one of the `module_dirs`, when the file is `um/winuser.zig` instead.
+fn import_root(mut path: UsePath, cx: &mut Cx) -> UsePath {
+    let file = if path.len() > 1 && cx.options.module_dirs.contains(&path[0]) {
+        let dir = path.remove(0);
+        format!("{}/{}.zig", dir, path[0])
+    } else {
+        format!("{}.zig", path[0])
+    };
+    cx.toplevel_imports.insert(path[0].clone(), file);
+    path
+}
+
+fn use_to_zig(u: &ItemUse, cx: &mut Cx, out: &mut dyn Write) -> Result<(), Error> {
+    for UseItem { path, name } in expand_use_tree(&u.tree)? {
+        cx.glob_import |= name.is_none();
+        let (path, in_scope) = resolve_use_path(path, cx)?;
+        let path = match path.first().map(String::as_str) {
+            None => continue,
+            // The C types are Zig primitives.
+            Some("ctypes") if !in_scope => continue,
+            Some(_) if !in_scope => import_root(path, cx),
+            Some(_) => path,
+        };
+        // Zig rejects the shadowing `const X = X;`, and doesn't need it.
+        if path.len() == 1 && name.as_ref() == Some(&path[0]) {
+            continue;
+        }
+        let vis = vis_to_zig(&u.vis);
+        let import = use_path_to_zig(&path);
+        match name {
+            Some(name) => writeln!(out, "{}const {} = {};", vis, zig_ident(&name), import)?,
+            // Zig can't import names individually by wildcard, but
+            // `usingnamespace` brings in all the public declarations.
+            None if cx.options.glob_as_usingnamespace => {
+                writeln!(out, "{}usingnamespace {};", vis, import)?
+            }
+            None => writeln!(out, "// Glob import of {} can't be expanded.", import)?,
+        }
+    }
+    Ok(())
+}
+
+/// Emit `#[doc]` attributes (`///` comments) as Zig doc comments, followed
+/// by a note for `#[deprecated]`, which Zig has no attribute for.
+fn docs_to_zig(attrs: &[Attribute], indent: &str, out: &mut dyn Write) -> Result<(), Error> {
+    doc_comments_to_zig(attrs, "///", indent, out)?;
+    if let Some(note) = deprecation(attrs) {
+        writeln!(out, "{}/// {}", indent, note)?;
+    }
+    Ok(())
+}
+
+/// Translate a function's doc comments, noting a `#[must_use]` too, since
+/// Zig has no such attribute. Zig does reject an ignored non-`void` result,
+/// but `_ =` silences that as easily as `let _ =` does in Rust.
+fn fn_docs_to_zig(attrs: &[Attribute], out: &mut dyn Write) -> Result<(), Error> {
+    docs_to_zig(attrs, "", out)?;
+    let attr = match attrs.iter().find(|attr| path_equals(&attr.path, "must_use")) {
+        Some(attr) => attr,
+        None => return Ok(()),
+    };
+    match attr.parse_meta() {
+        Ok(Meta::NameValue(nv)) => match &nv.lit {
+            Lit::Str(s) => writeln!(out, "/// Must use return value: {}", s.value())?,
+            _ => writeln!(out, "/// Must use return value.")?,
+        },
+        _ => writeln!(out, "/// Must use return value.")?,
+    }
+    Ok(())
+}
+
+/// Describe a `#[deprecated]`, `#[deprecated = "note"]`, or
+/// `#[deprecated(since = "...", note = "...")]` attribute.
+fn deprecation(attrs: &[Attribute]) -> Option<String> {
+    let attr = attrs.iter().find(|attr| path_equals(&attr.path, "deprecated"))?;
+    let (mut since, mut note) = (None, None);
+    match attr.parse_meta() {
+        Ok(Meta::NameValue(nv)) => {
+            if let Lit::Str(s) = &nv.lit {
+                note = Some(s.value());
+            }
+        }
+        Ok(Meta::List(list)) => {
+            for nested in &list.nested {
+                if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
+                    if let Lit::Str(s) = &nv.lit {
+                        if path_equals(&nv.path, "since") {
+                            since = Some(s.value());
+                        } else if path_equals(&nv.path, "note") {
+                            note = Some(s.value());
+                        }
+                    }
+                }
+            }
+        }
+        _ => (),
+    }
+    let mut out = String::from("Deprecated");
+    if let Some(since) = since {
+        out.push_str(&format!(" since {}", since));
+    }
+    match note {
+        Some(note) => out.push_str(&format!(": {}", note)),
+        None => out.push('.'),
+    }
+    Some(out)
+}
+
+/// Emit `#[doc]` attributes as Zig comments starting with `marker`, which is
+/// `///` for an item or `//!` for the file.
+fn doc_comments_to_zig(
+    attrs: &[Attribute],
+    marker: &str,
+    indent: &str,
+    out: &mut dyn Write,
+) -> Result<(), Error> {
+    for attr in attrs {
+        if !path_equals(&attr.path, "doc") {
+            continue;
+        }
+        if let Ok(Meta::NameValue(nv)) = attr.parse_meta() {
+            if let Lit::Str(s) = &nv.lit {
+                for line in s.value().split('\n') {
+                    let line = line.trim_end();
+                    // `///` comments keep their leading space; block docs may not.
+                    let sep = if line.is_empty() || line.starts_with(' ') { "" } else { " " };
+                    writeln!(out, "{}{}{}{}", indent, marker, sep, line)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Translate a const, with a type annotation only where Zig can't infer the
+/// type from the value: integers are best left as `comptime_int`, but a
+/// `null` or array needs to know what it is.
+fn const_to_zig(c: &ItemConst, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    let name = c.ident.to_string();
+    const_decl_to_zig(&c.attrs, &c.vis, &name, &c.ty, &c.expr, cx, out)
+}
+
+/// Translate the parts of a constant, which may be a free item or an
+/// associated one.
+fn const_decl_to_zig(
+    attrs: &[Attribute],
+    vis: &Visibility,
+    name: &str,
+    ty: &Type,
+    expr: &Expr,
+    cx: &Cx,
+    out: &mut dyn Write,
+) -> Result<(), Error> {
+    docs_to_zig(attrs, "", out)?;
+    let vis = vis_to_zig(vis);
+    let init = init_to_zig(expr, ty, cx)?;
+    match ty {
+        Type::Ptr(_) | Type::Array(_) | Type::BareFn(_) => {
+            let ty = ty_to_zig(ty, cx)?;
+            writeln!(out, "{}const {}: {} = {};", vis, zig_ident(name), ty, init)?;
+        }
+        _ => writeln!(out, "{}const {} = {};", vis, zig_ident(name), init)?,
+    }
+    Ok(())
+}
+
+fn static_to_zig(st: &ItemStatic, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    docs_to_zig(&st.attrs, "", out)?;
+    let vis = vis_to_zig(&st.vis);
+    let decl = if st.mutability.is_some() { "var" } else { "const" };
+    let ty = ty_to_zig(&st.ty, cx)?;
+    let init = init_to_zig(&st.expr, &st.ty, cx)?;
+    writeln!(out, "{}{} {}: {} = {};", vis, decl, zig_ident(&st.ident.to_string()), ty, init)?;
+    Ok(())
+}
+
+/// Translate a type alias.
+///
+/// A generic alias becomes the Zig idiom for a generic type, a function from
+/// its `comptime` parameters to a `type`: `type P<T> = *mut T` is
+/// `fn P(comptime T: type) type { return ?*T; }`. Lifetimes have no Zig
+/// counterpart and are dropped, and bounds only constrain the Rust side.
+fn type_to_zig(t: &ItemType, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    docs_to_zig(&t.attrs, "", out)?;
+    let vis = vis_to_zig(&t.vis);
+    let ident = zig_ident(&t.ident.to_string());
+    let ty = match c_string_to_zig(&t.ty) {
+        Some(ty) if cx.options.c_strings => ty,
+        _ => ty_to_zig(&t.ty, cx)?,
+    };
+    let mut params = Vec::new();
+    for param in &t.generics.params {
+        match param {
+            GenericParam::Type(p) => {
+                params.push(format!("comptime {}: type", zig_ident(&p.ident.to_string())))
+            }
+            GenericParam::Const(c) => {
+                let name = zig_ident(&c.ident.to_string());
+                params.push(format!("comptime {}: {}", name, ty_to_zig(&c.ty, cx)?))
+            }
+            GenericParam::Lifetime(_) => (),
+        }
+    }
+    if params.is_empty() {
+        writeln!(out, "{}const {} = {};", vis, ident, ty)?;
+        return Ok(());
+    }
+    writeln!(out, "{}fn {}({}) type {{", vis, ident, params.join(", "))?;
+    writeln!(out, "    return {};", ty)?;
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+/// The null-terminated Zig pointer for a C string pointer type: a pointer to
+/// `c_char` or `wchar_t`, or to winapi's `CHAR` and `WCHAR` aliases of them.
+///
+/// Zig's `u8` and `u16` are what its own string literals are made of, so
+/// `*const c_char` is `[*:0]const u8`.
+fn c_string_to_zig(ty: &Type) -> Option<String> {
+    let p = match ty {
+        Type::Ptr(p) => p,
+        _ => return None,
+    };
+    let elem = match &*p.elem {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    if !elem.arguments.is_empty() {
+        return None;
+    }
+    let elem = match elem.ident.to_string().as_str() {
+        "c_char" | "CHAR" => "u8",
+        "wchar_t" | "WCHAR" => "u16",
+        _ => return None,
+    };
+    let mut_str = if p.const_token.is_some() { "const " } else { "" };
+    Some(format!("[*:0]{}{}", mut_str, elem))
+}
+
+/// The Zig name for parameter `index`, which is `argN` when the pattern
+/// isn't a plain name.
+fn arg_name(pat: &Pat, index: usize) -> String {
+    match pat {
+        Pat::Ident(i) => zig_ident(&i.ident.to_string()),
+        Pat::Wild(_) => "_".to_string(),
+        _ => format!("arg{}", index),
+    }
+}
+
+fn fn_arg_to_zig(arg: &FnArg, index: usize, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    if let FnArg::Typed(t) = arg {
+        writeln!(out, "    {}: {},", arg_name(&t.pat, index), ty_to_zig(&t.ty, cx)?)?;
+    }
+    Ok(())
+}
+
+fn foreign_mod_to_zig(fm: &ItemForeignMod, cx: &mut Cx, out: &mut dyn Write) -> Result<(), Error> {
+    //println!("{:#?}", fm);
+    let callconv = abi_to_callconv(Some(&fm.abi), cx)?;
+    let lib = link_lib(&fm.attrs)
+        .or_else(|| cx.module.as_ref().and_then(|m| cx.options.link_libs.get(m)).cloned())
+        .unwrap_or_else(|| cx.options.default_lib.clone());
+    let mut decls = Decls::default();
+    for item in &fm.items {
+        let mut decl = Vec::new();
+        let out = &mut decl;
+        match item {
+            ForeignItem::Fn(f) => {
+                if !cfg_to_zig(&f.attrs, cx, "", out)? {
+                    continue;
+                }
+                fn_docs_to_zig(&f.attrs, out)?;
+                let vis = vis_to_zig(&f.vis);
+                let ident = f.sig.ident.to_string();
+                let symbol = attr_str_value(&f.attrs, "link_name").unwrap_or_else(|| ident.clone());
+                writeln!(out, "{}extern \"{}\" fn {} (", vis, lib, zig_ident(&symbol))?;
+                for (i, arg) in f.sig.inputs.iter().enumerate() {
+                    fn_arg_to_zig(arg, i, cx, out)?;
+                }
+                if f.sig.variadic.is_some() {
+                    writeln!(out, "    ...")?;
+                }
+                let ret = ret_ty_to_zig(&f.sig.output, cx)?;
+                writeln!(out, ") callconv({}) {};", callconv, ret)?;
+                // Keep the Rust name usable when the symbol is renamed.
+                if symbol != ident {
+                    writeln!(out, "{}const {} = {};", vis, zig_ident(&ident), zig_ident(&symbol))?;
+                }
+            }
+            _ => {
+                let (name, span) = match item {
+                    ForeignItem::Static(s) => (Some(s.ident.to_string()), s.ident.span()),
+                    ForeignItem::Type(t) => (Some(t.ident.to_string()), t.ident.span()),
+                    _ => (None, fm.abi.extern_token.span),
+                };
+                cx.diagnose("foreign item", name, "Unhandled foreign item", span);
+            }
+        }
+        decls.push(&decl);
+    }
+    out.write_all(&decls.into_inner())?;
+    Ok(())
+}
+
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { path, .. }) => match path.segments.last() {
+            Some(seg) => seg.ident == "PhantomData",
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// The prefix of the names standing in for inline unions in a `STRUCT!`.
+const INLINE_UNION: &str = "__inline_union_";
+
+/// Take the anonymous `union { ... }` field types out of a `STRUCT!` body,
+/// leaving a name in their place so the rest parses as an ordinary struct.
+///
+/// The unions' fields come back in order, the Nth replaced by
+/// `__inline_union_N`.
+fn take_inline_unions(toks: &TokenStream) -> Result<(TokenStream, Vec<FieldsNamed>), Error> {
+    let mut unions = Vec::new();
+    let mut stripped = TokenStream::new();
+    for tt in toks.clone() {
+        let body = match tt {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Brace => g,
+            tt => {
+                stripped.extend(Some(tt));
+                continue;
+            }
+        };
+        let mut fields = TokenStream::new();
+        let mut tts = body.stream().into_iter().peekable();
+        while let Some(tt) = tts.next() {
+            let union_body = match (&tt, tts.peek()) {
+                (TokenTree::Ident(i), Some(TokenTree::Group(g)))
+                    if i == "union" && g.delimiter() == Delimiter::Brace =>
+                {
+                    TokenStream::from(tts.next().unwrap())
+                }
+                _ => {
+                    fields.extend(Some(tt));
+                    continue;
+                }
+            };
+            let union = syn::parse2(union_body).map_err(|e| Error::Macro("STRUCT", e))?;
+            let name = format!("{}{}", INLINE_UNION, unions.len());
+            unions.push(union);
+            fields.extend(Some(TokenTree::Ident(Ident::new(&name, tt.span()))));
+        }
+        let mut group = Group::new(Delimiter::Brace, fields);
+        group.set_span(body.span());
+        stripped.extend(Some(TokenTree::Group(group)));
+    }
+    Ok((stripped, unions))
+}
+
+/// The inline union a field's type stands in for, if it's one.
+fn inline_union<'a>(ty: &Type, unions: &'a [FieldsNamed]) -> Option<&'a FieldsNamed> {
+    let ident = match ty {
+        Type::Path(TypePath { qself: None, path }) => path_as_single_ident(path)?,
+        _ => return None,
+    };
+    let index: usize = ident.strip_prefix(INLINE_UNION)?.parse().ok()?;
+    unions.get(index)
+}
+
+fn struct_macro_to_zig(toks: &TokenStream, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    // The body is an ordinary struct item, leading docs and attributes
+    // included, except that a field can be an anonymous union, as in
+    // `u: union { Offset: DWORD, Pointer: PVOID },`.
+    let (toks, unions) = take_inline_unions(toks)?;
+    let s: ItemStruct = syn::parse2(toks).map_err(|e| Error::Macro("STRUCT", e))?;
+    // Zig's `packed struct` is bit-packed and can't hold arrays, so C packing
+    // is expressed as underaligned fields of an `extern struct` instead. Zig
+    // also has no struct-level alignment, but aligning the first field raises
+    // the alignment of the whole struct.
+    let repr = struct_repr(&s.attrs);
+    // Zero-sized markers have no place in a C layout. Tuple struct fields
+    // keep their Rust position as their name.
+    let mut fields = s.fields.iter().enumerate().filter(|(_, f)| !is_phantom_data(&f.ty));
+    // A transparent newtype has the same ABI as its one field, so it's just
+    // another name for the field's type.
+    if repr.transparent {
+        return match (fields.next(), fields.next()) {
+            (Some((_, f)), None) => {
+                docs_to_zig(&s.attrs, "", out)?;
+                let name = zig_ident(&s.ident.to_string());
+                writeln!(out, "pub const {} = {};", name, ty_to_zig(&f.ty, cx)?)?;
+                Ok(())
+            }
+            _ => Err(Error::Unhandled("repr(transparent) without exactly one field".into())),
+        };
+    }
+    // Packing to N > 1 caps each field at its natural alignment, which we
+    // can't know for typedefs.
+    if let Some(packed) = &repr.packed {
+        if packed != "1" {
+            return Err(Error::Unhandled(format!("repr(packed({}))", packed)));
+        }
+    }
+    docs_to_zig(&s.attrs, "", out)?;
+    writeln!(out, "pub const {} = extern struct {{", zig_ident(&s.ident.to_string()))?;
+    fields_to_zig(fields, &repr, &unions, cx, out)?;
+    assoc_consts_to_zig(&s.ident, cx, out)?;
+    writeln!(out, "}};")?;
+    size_assert_to_zig(&s.ident, cx, out)
+}
+
+/// Translate the fields of a struct or union, given with their positions,
+/// aligned as its `#[repr]` requires.
+///
+/// A field standing in for one of the `unions` from `take_inline_unions`
+/// becomes a nested `extern union`.
+fn fields_to_zig<'a>(
+    fields: impl Iterator<Item = (usize, &'a Field)>,
+    repr: &StructRepr,
+    unions: &[FieldsNamed],
+    cx: &Cx,
+    out: &mut dyn Write,
+) -> Result<(), Error> {
+    let mut first = true;
+    for (pos, f) in fields {
+        if !cfg_to_zig(&f.attrs, cx, "    ", out)? {
+            continue;
+        }
+        docs_to_zig(&f.attrs, "    ", out)?;
+        let name = match &f.ident {
+            Some(ident) => zig_ident(&ident.to_string()),
+            None => format!("@\"{}\"", pos),
+        };
+        let ty = match inline_union(&f.ty, unions) {
+            Some(union) => {
+                let mut ty = String::from("extern union {\n");
+                for f in &union.named {
+                    let mut docs = Vec::new();
+                    if !cfg_to_zig(&f.attrs, cx, "        ", &mut docs)? {
+                        continue;
+                    }
+                    docs_to_zig(&f.attrs, "        ", &mut docs)?;
+                    ty.push_str(&String::from_utf8(docs).unwrap());
+                    let name = zig_ident(&f.ident.as_ref().unwrap().to_string());
+                    ty.push_str(&format!("        {}: {},\n", name, ty_to_zig(&f.ty, cx)?));
+                }
+                ty.push_str("    }");
+                ty
+            }
+            None => ty_to_zig(&f.ty, cx)?,
+        };
+        let align = match (&repr.packed, &repr.align) {
+            (Some(packed), _) => Some(packed),
+            (None, Some(align)) if first => Some(align),
+            _ => None,
+        };
+        first = false;
+        match align {
+            Some(align) => writeln!(out, "    {}: {} align({}),", name, ty, align)?,
+            None => writeln!(out, "    {}: {},", name, ty)?,
+        }
+    }
+    Ok(())
+}
+
+/// Write the associated constants collected for the struct or union `ident`
+/// by `namespaced_consts`, after its fields.
+fn assoc_consts_to_zig(ident: &Ident, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    match cx.assoc_consts.get(&ident.to_string()) {
+        Some(consts) if !consts.is_empty() => {
+            writeln!(out)?;
+            out.write_all(consts)?;
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+/// Follow a struct or union with the assertion of its size, if there's a
+/// `const_assert!` for it.
+fn size_assert_to_zig(ident: &Ident, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    if let Some(size) = cx.size_asserts.get(&ident.to_string()) {
+        writeln!(
+            out,
+            "comptime {{\n    @import(\"std\").debug.assert(@sizeOf({}) == {});\n}}",
+            zig_ident(&ident.to_string()),
+            size
+        )?;
+    }
+    Ok(())
+}
+
+/// Translate a native Rust `union`, which bindings newer than winapi's
+/// `UNION!` macro use.
+///
+/// Its fields are laid out like a `STRUCT!`'s, overlapping instead.
+fn union_to_zig(u: &ItemUnion, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    if !u.generics.params.is_empty() {
+        return Err(Error::Nyi);
+    }
+    let repr = struct_repr(&u.attrs);
+    if repr.transparent {
+        return Err(Error::Unhandled("repr(transparent) union".into()));
+    }
+    if let Some(packed) = &repr.packed {
+        if packed != "1" {
+            return Err(Error::Unhandled(format!("repr(packed({}))", packed)));
+        }
+    }
+    docs_to_zig(&u.attrs, "", out)?;
+    let name = zig_ident(&u.ident.to_string());
+    writeln!(out, "{}const {} = extern union {{", vis_to_zig(&u.vis), name)?;
+    let fields = u.fields.named.iter().enumerate().filter(|(_, f)| !is_phantom_data(&f.ty));
+    fields_to_zig(fields, &repr, &[], cx, out)?;
+    assoc_consts_to_zig(&u.ident, cx, out)?;
+    writeln!(out, "}};")?;
+    size_assert_to_zig(&u.ident, cx, out)
+}
+
+/// The body of winapi's `UNION!` macro.
+///
+/// The macro takes the union's storage as one or two array types (the second
+/// for 64-bit targets), then fields as `name name_mut: Type`, where the two
+/// idents name the generated accessor methods.
+struct UnionMacro {
+    ident: Ident,
+    fields: Vec<(Ident, Type)>,
+}
+
+impl Parse for UnionMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Attribute::parse_outer(input)?;
+        input.parse::<Token![union]>()?;
+        let ident = input.parse()?;
+        let content;
+        braced!(content in input);
+        while content.peek(token::Bracket) {
+            content.parse::<TypeArray>()?;
+        }
+        content.parse::<Token![,]>()?;
+        let mut fields = Vec::new();
+        while !content.is_empty() {
+            let name = content.parse()?;
+            content.parse::<Ident>()?;
+            content.parse::<Token![:]>()?;
+            fields.push((name, content.parse()?));
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+        Ok(UnionMacro { ident, fields })
+    }
+}
+
+/// Translate a `UNION!` macro.
+///
+/// The storage arrays are dropped, as an `extern union` is already sized to
+/// its largest field, and the accessor methods become plain field access.
+fn union_macro_to_zig(toks: &TokenStream, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    let u: UnionMacro = syn::parse2(toks.to_owned()).map_err(|e| Error::Macro("UNION", e))?;
+    writeln!(out, "pub const {} = extern union {{", zig_ident(&u.ident.to_string()))?;
+    for (name, ty) in &u.fields {
+        writeln!(out, "    {}: {},", zig_ident(&name.to_string()), ty_to_zig(ty, cx)?)?;
+    }
+    assoc_consts_to_zig(&u.ident, cx, out)?;
+    writeln!(out, "}};")?;
+    Ok(())
+}
+
+/// A `GUID` struct literal from its eleven numeric components.
+fn guid_to_zig(parts: &[String]) -> Result<String, Error> {
+    if parts.len() != 11 {
+        return Err(Error::Nyi);
+    }
+    Ok(format!(
+        "GUID{{ .Data1 = {}, .Data2 = {}, .Data3 = {}, .Data4 = .{{ {} }} }}",
+        parts[0],
+        parts[1],
+        parts[2],
+        parts[3..].join(", ")
+    ))
+}
+
+/// Translate `DEFINE_GUID!{NAME, l, w1, w2, b1, ..., b8}` into a `GUID` const.
+fn define_guid_to_zig(toks: &TokenStream, out: &mut dyn Write) -> Result<(), Error> {
+    let parser = Punctuated::<Expr, Token![,]>::parse_terminated;
+    let args = parser.parse2(toks.to_owned()).map_err(|e| Error::Macro("DEFINE_GUID", e))?;
+    let mut args = args.iter();
+    let ident = match args.next() {
+        Some(Expr::Path(p)) => path_as_single_ident(&p.path).ok_or(Error::Nyi)?,
+        _ => return Err(Error::Nyi),
+    };
+    let mut parts = Vec::new();
+    for arg in args {
+        match arg {
+            Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => parts.push(lit_int_to_zig(i)),
+            _ => return Err(Error::Nyi),
+        }
+    }
+    writeln!(out, "pub const {} = {};", zig_ident(&ident), guid_to_zig(&parts)?)?;
+    Ok(())
+}
+
+/// A method in a `RIDL!` interface.
+struct RidlMethod {
+    ident: Ident,
+    inputs: Punctuated<BareFnArg, Token![,]>,
+    output: ReturnType,
+}
+
+/// The body of winapi's `RIDL!` macro, for interfaces.
+///
+/// ```text
+/// #[uuid(...)]
+/// interface IFoo(IFooVtbl): IUnknown(IUnknownVtbl) {
+///     fn Method(arg: Type,) -> HRESULT,
+/// }
+/// ```
+struct RidlMacro {
+    uuid: Vec<String>,
+    ident: Ident,
+    vtbl: Ident,
+    parent_vtbl: Option<Ident>,
+    methods: Vec<RidlMethod>,
+}
+
+impl Parse for RidlMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut uuid = Vec::new();
+        for attr in Attribute::parse_outer(input)? {
+            if let Ok(Meta::List(list)) = attr.parse_meta() {
+                if path_equals(&list.path, "uuid") {
+                    for nested in &list.nested {
+                        if let NestedMeta::Lit(Lit::Int(i)) = nested {
+                            uuid.push(lit_int_to_zig(i));
+                        }
+                    }
+                }
+            }
+        }
+        let kw: Ident = input.parse()?;
+        if kw != "interface" {
+            return Err(syn::Error::new(kw.span(), "expected `interface`"));
+        }
+        let ident = input.parse()?;
+        let content;
+        parenthesized!(content in input);
+        let vtbl = content.parse()?;
+        let parent_vtbl = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            input.parse::<Ident>()?;
+            let content;
+            parenthesized!(content in input);
+            Some(content.parse()?)
+        } else {
+            None
+        };
+        let content;
+        braced!(content in input);
+        let mut methods = Vec::new();
+        while !content.is_empty() {
+            content.parse::<Token![fn]>()?;
+            let ident = content.parse()?;
+            let args;
+            parenthesized!(args in content);
+            let inputs = args.parse_terminated(BareFnArg::parse)?;
+            let output = content.parse()?;
+            methods.push(RidlMethod {
+                ident,
+                inputs,
+                output,
+            });
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+        Ok(RidlMacro {
+            uuid,
+            ident,
+            vtbl,
+            parent_vtbl,
+            methods,
+        })
+    }
+}
+
+/// Translate a `RIDL!` COM interface.
+///
+/// This follows winapi's own layout: the vtable is an `extern struct` of
+/// function pointers, led by a `parent` field holding the parent interface's
+/// vtable, and the interface is an `extern struct` holding only `lpVtbl`.
+/// The interface's IID is attached to it as a `uuid` declaration, followed
+/// by any associated constants.
+fn ridl_macro_to_zig(toks: &TokenStream, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    let r: RidlMacro = syn::parse2(toks.to_owned()).map_err(|e| Error::Macro("RIDL", e))?;
+    let callconv = abi_name_to_callconv("system", cx)?;
+    writeln!(out, "pub const {} = extern struct {{", zig_ident(&r.vtbl.to_string()))?;
+    if let Some(parent) = &r.parent_vtbl {
+        writeln!(out, "    parent: {},", parent)?;
+    }
+    for m in &r.methods {
+        let mut args = vec![format!("This: *{}", r.ident)];
+        args.extend(bare_fn_args_to_zig(&m.inputs, cx)?);
+        let ret = ret_ty_to_zig(&m.output, cx)?;
+        writeln!(
+            out,
+            "    {}: *const fn({}) callconv({}) {},",
+            zig_ident(&m.ident.to_string()),
+            args.join(", "),
+            callconv,
+            ret
+        )?;
+    }
+    writeln!(out, "}};")?;
+    writeln!(out, "pub const {} = extern struct {{", zig_ident(&r.ident.to_string()))?;
+    writeln!(out, "    lpVtbl: *const {},", r.vtbl)?;
+    writeln!(out, "    pub const uuid = {};", guid_to_zig(&r.uuid)?)?;
+    assoc_consts_to_zig(&r.ident, cx, out)?;
+    writeln!(out, "}};")?;
+    Ok(())
+}
+
+/// Translate winapi's `ENUM!{enum Name { A = 1, B, }}` macro.
+///
+/// The body is plain Rust enum syntax, but every `ENUM!` is public and the
+/// variants are C enumerators, so it becomes a `c_int` enum.
+fn enum_macro_to_zig(toks: &TokenStream, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    let mut e: ItemEnum = syn::parse2(toks.to_owned()).map_err(|e| Error::Macro("ENUM", e))?;
+    e.vis = Visibility::Public(VisPublic {
+        pub_token: Default::default(),
+    });
+    enum_to_zig(&e, cx, out)
+}
+
+/// The body of winapi's `DECLARE_HANDLE!{HANDLE, OPAQUE}` macro.
+///
+/// The opaque type's name may be left out, in which case it's the handle's
+/// name with `__` appended, as winapi spells them.
+struct DeclareHandleMacro {
+    handle: Ident,
+    opaque: Ident,
+}
+
+impl Parse for DeclareHandleMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let handle: Ident = input.parse()?;
+        let opaque = if input.is_empty() {
+            None
+        } else {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                None
+            } else {
+                Some(input.parse()?)
+            }
+        };
+        let _: Option<Token![,]> = input.parse()?;
+        if !input.is_empty() {
+            return Err(input.error("unexpected token"));
+        }
+        let opaque = opaque.unwrap_or_else(|| Ident::new(&format!("{}__", handle), handle.span()));
+        Ok(DeclareHandleMacro { handle, opaque })
+    }
+}
+
+fn declare_handle_to_zig(toks: &TokenStream, out: &mut dyn Write) -> Result<(), Error> {
+    let d: DeclareHandleMacro = syn::parse2(toks.to_owned())
+        .map_err(|e| Error::Macro("DECLARE_HANDLE", e))?;
+    let opaque = zig_ident(&d.opaque.to_string());
+    writeln!(out, "pub const {} = opaque {{}};", opaque)?;
+    writeln!(out, "pub const {} = ?*{};", zig_ident(&d.handle.to_string()), opaque)?;
+    Ok(())
+}
+
+/// The body of winapi's `BITFIELD!` macro, which adds accessors for bit
+/// ranges of an integer field of a struct.
+///
+/// ```text
+/// DCB BitFields: DWORD [
+///     fBinary set_fBinary[0..1],
+///     fParity set_fParity[1..2],
+/// ]
+/// ```
+struct BitfieldMacro {
+    struct_ident: Ident,
+    field: Ident,
+    ty: Type,
+    /// Each accessor's name and its bit range, end exclusive.
+    bits: Vec<(Ident, u32, u32)>,
+}
+
+impl Parse for BitfieldMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let struct_ident = input.parse()?;
+        let field = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        let content;
+        bracketed!(content in input);
+        let mut bits = Vec::new();
+        while !content.is_empty() {
+            let name = content.parse()?;
+            content.parse::<Ident>()?;
+            let range;
+            bracketed!(range in content);
+            let start = range.parse::<LitInt>()?.base10_parse()?;
+            range.parse::<Token![..]>()?;
+            let end = range.parse::<LitInt>()?.base10_parse()?;
+            bits.push((name, start, end));
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+        Ok(BitfieldMacro {
+            struct_ident,
+            field,
+            ty,
+            bits,
+        })
+    }
+}
+
+/// The width in bits of an unsigned integer type or typedef of one.
+fn uint_width(name: &str) -> Option<u32> {
+    match name {
+        "u8" | "c_uchar" | "__uint8" | "BYTE" | "UCHAR" | "UINT8" => Some(8),
+        "u16" | "c_ushort" | "__uint16" | "WORD" | "USHORT" | "UINT16" => Some(16),
+        "u32" | "c_uint" | "c_ulong" | "__uint32" | "DWORD" | "UINT" | "ULONG" | "UINT32"
+        | "DWORD32" | "ULONG32" => Some(32),
+        "u64" | "c_ulonglong" | "__uint64" | "ULONGLONG" | "DWORD64" | "UINT64" | "ULONG64" => {
+            Some(64)
+        }
+        _ => None,
+    }
+}
+
+/// Translate `BITFIELD!` into a `packed struct` named `Struct_field`, which
+/// the field can be `@bitCast` to.
+///
+/// Zig orders a packed struct's fields from the least significant bit, as
+/// the ranges count, and its backing integer must be exactly filled, so any
+/// bits between or after the ranges get `_reservedN` padding fields.
+fn bitfield_macro_to_zig(toks: &TokenStream, out: &mut dyn Write) -> Result<(), Error> {
+    let b: BitfieldMacro = syn::parse2(toks.to_owned())
+        .map_err(|e| Error::Macro("BITFIELD", e))?;
+    let width = match &b.ty {
+        Type::Path(TypePath { path, qself: None }) => {
+            path_as_single_ident(path).and_then(|name| uint_width(&name))
+        }
+        _ => None,
+    };
+    let width = width.ok_or_else(|| Error::Unhandled("BITFIELD! of unknown unsigned type".into()))?;
+    writeln!(out, "pub const {}_{} = packed struct(u{}) {{", b.struct_ident, b.field, width)?;
+    let mut next = 0;
+    for (name, start, end) in &b.bits {
+        if *start < next || end <= start || *end > width {
+            return Err(Error::Unhandled(format!("bit range {}..{}", start, end)));
+        }
+        if *start > next {
+            writeln!(out, "    _reserved{}: u{},", next, start - next)?;
+        }
+        writeln!(out, "    {}: u{},", zig_ident(&name.to_string()), end - start)?;
+        next = *end;
+    }
+    if next < width {
+        writeln!(out, "    _reserved{}: u{},", next, width - next)?;
+    }
+    writeln!(out, "}};")?;
+    Ok(())
+}
+
+/// Translate winapi's `FN!{stdcall NAME(args) -> ret}` callback typedefs.
+///
+/// The macro defines an `Option` of an `extern "system"` (for `stdcall`) or
+/// `extern "C"` (for `cdecl`) fn, so this is a nullable Zig fn pointer.
+fn fn_typedef_macro_to_zig(toks: &TokenStream, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    let mut tok_iter = toks.clone().into_iter();
+    let abi = match tok_iter.next() {
+        Some(TokenTree::Ident(callconv)) if callconv == "stdcall" => "system",
+        Some(TokenTree::Ident(callconv)) if callconv == "cdecl" => "C",
+        Some(TokenTree::Ident(callconv)) => return Err(Error::Unhandled(callconv.to_string())),
+        _ => return Err(Error::Nyi),
+    };
+    let ident = match tok_iter.next() {
+        Some(TokenTree::Ident(ident)) => ident,
+        _ => return Err(Error::Nyi),
+    };
+    let mut inner_fn: TokenStream = format!("extern \"{}\" fn", abi).parse().unwrap();
+    inner_fn.extend(tok_iter);
+    let bare_fn: TypeBareFn = syn::parse2(inner_fn).map_err(|e| Error::Macro("FN", e))?;
+    let ident = zig_ident(&ident.to_string());
+    writeln!(out, "pub const {} = {};", ident, bare_fn_ty_to_zig(&bare_fn, cx)?)?;
+    Ok(())
+}
+
+fn macro_to_zig(m: &ItemMacro, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    if let Some(id) = path_as_single_ident(&m.mac.path) {
+        match id.as_str() {
+            "STRUCT" => struct_macro_to_zig(&m.mac.tokens, cx, out),
+            "ENUM" => enum_macro_to_zig(&m.mac.tokens, cx, out),
+            "UNION" => union_macro_to_zig(&m.mac.tokens, cx, out),
+            "RIDL" => ridl_macro_to_zig(&m.mac.tokens, cx, out),
+            "DEFINE_GUID" => define_guid_to_zig(&m.mac.tokens, out),
+            "DECLARE_HANDLE" => declare_handle_to_zig(&m.mac.tokens, out),
+            "FN" => fn_typedef_macro_to_zig(&m.mac.tokens, cx, out),
+            "BITFIELD" => bitfield_macro_to_zig(&m.mac.tokens, out),
+            // Already taken into account by `flag_enums`.
+            "DEFINE_ENUM_FLAG_OPERATORS" => Ok(()),
+            // Emitted after the struct, from `size_asserts`.
+            "const_assert" if size_assert(&m.mac.tokens).is_some() => Ok(()),
+            _ => Err(Error::Unhandled(id)),
+        }
+    } else {
+        Err(Error::Nyi)
+    }
+}
+
+/// The contents of all `#[repr(...)]` attributes.
+fn repr_metas(attrs: &[Attribute]) -> Vec<NestedMeta> {
+    let mut metas = Vec::new();
+    for attr in attrs {
+        if !path_equals(&attr.path, "repr") {
+            continue;
+        }
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            metas.extend(list.nested);
+        }
+    }
+    metas
+}
+
+/// Find the integer type an enum's `#[repr(...)]` gives its tags, if any.
+///
+/// `repr(C)` is the default, which is `c_int` for a C enum. Any other repr,
+/// like `transparent` or `align(N)`, has no Zig counterpart on an enum.
+fn enum_repr(attrs: &[Attribute]) -> Result<Option<String>, Error> {
+    let mut int = None;
+    for nested in repr_metas(attrs) {
+        match &nested {
+            NestedMeta::Meta(Meta::Path(p)) if path_equals(p, "C") => (),
+            NestedMeta::Meta(Meta::Path(p)) => match path_as_single_ident(p) {
+                Some(ident) if is_rust_primitive(&ident) && !ident.starts_with('f') => {
+                    int = Some(ident)
+                }
+                _ => return Err(Error::Unhandled("enum repr other than an integer".into())),
+            },
+            _ => return Err(Error::Unhandled("enum repr other than an integer".into())),
+        }
+    }
+    Ok(int)
+}
+
+/// The alignments requested by `#[repr(packed)]` and `#[repr(align(N))]`,
+/// and whether it's `#[repr(transparent)]`.
+#[derive(Default)]
+struct StructRepr {
+    packed: Option<String>,
+    align: Option<String>,
+    transparent: bool,
+}
+
+fn struct_repr(attrs: &[Attribute]) -> StructRepr {
+    fn int_arg(list: &MetaList) -> Option<String> {
+        match list.nested.first() {
+            Some(NestedMeta::Lit(Lit::Int(i))) => Some(i.base10_digits().to_string()),
+            _ => None,
+        }
+    }
+    let mut repr = StructRepr::default();
+    for nested in repr_metas(attrs) {
+        match nested {
+            NestedMeta::Meta(Meta::Path(p)) if path_equals(&p, "packed") => {
+                repr.packed = Some("1".into());
+            }
+            NestedMeta::Meta(Meta::Path(p)) if path_equals(&p, "transparent") => {
+                repr.transparent = true;
+            }
+            NestedMeta::Meta(Meta::List(list)) if path_equals(&list.path, "packed") => {
+                repr.packed = int_arg(&list);
+            }
+            NestedMeta::Meta(Meta::List(list)) if path_equals(&list.path, "align") => {
+                repr.align = int_arg(&list);
+            }
+            _ => (),
+        }
+    }
+    repr
+}
+
+fn enum_to_zig(e: &ItemEnum, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    let vis = vis_to_zig(&e.vis);
+    let backing = enum_repr(&e.attrs)?.unwrap_or_else(|| "c_int".into());
+    docs_to_zig(&e.attrs, "", out)?;
+    writeln!(out, "{}const {} = enum({}) {{", vis, zig_ident(&e.ident.to_string()), backing)?;
+    for v in &e.variants {
+        if !cfg_to_zig(&v.attrs, cx, "    ", out)? {
+            continue;
+        }
+        let name = zig_ident(&v.ident.to_string());
+        // Zig numbers implicit tags from the previous one, like C and Rust.
+        match &v.discriminant {
+            Some((_, expr)) => writeln!(out, "    {} = {},", name, expr_to_zig(expr, cx)?)?,
+            None => writeln!(out, "    {},", name)?,
+        }
+    }
+    // A flags enum is non-exhaustive, so that `@enumFromInt` of the flags
+    // or'd together is allowed.
+    if cx.flag_enums.contains(&e.ident.to_string()) {
+        writeln!(out, "    _,")?;
+    }
+    writeln!(out, "}};")?;
+    Ok(())
+}
+
+/// Find the enums named by `DEFINE_ENUM_FLAG_OPERATORS!`, including in
+/// inline modules.
+///
+/// These are collected before translating, since the macro follows the enum
+/// it applies to.
+fn flag_enums(items: &[Item]) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for item in items {
+        match item {
+            Item::Macro(m) if m.mac.path.is_ident("DEFINE_ENUM_FLAG_OPERATORS") => {
+                if let Ok(ident) = syn::parse2::<Ident>(m.mac.tokens.clone()) {
+                    names.insert(ident.to_string());
+                }
+            }
+            Item::Mod(ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => names.extend(flag_enums(items)),
+            _ => (),
+        }
+    }
+    names
+}
+
+/// A tuple struct, which a call can construct.
+struct TupleStruct {
+    transparent: bool,
+    /// The positions of the fields kept in Zig, which are all but the
+    /// `PhantomData` markers.
+    fields: Vec<usize>,
+}
+
+/// Whether `ty` is a pointer, or else a number, if that's known from `ty`
+/// itself, the type map, the file's `typedef_ptrs`, or winapi's typedefs.
+fn is_ptr_type(ty: &Type, typedef_ptrs: &HashMap<String, bool>, options: &Options) -> Option<bool> {
+    let name = match ty {
+        Type::Ptr(_) => return Some(true),
+        Type::Path(TypePath { qself: None, path }) => path.segments.last()?.ident.to_string(),
+        _ => return None,
+    };
+    if let Some(zig) = options.type_map.get(&name) {
+        return Some(zig.starts_with('*') || zig.starts_with("?*") || zig.starts_with("[*"));
+    }
+    if is_rust_primitive(&name) || ctype_to_zig(&name).is_some_and(|zig| zig != "anyopaque") {
+        return Some(false);
+    }
+    if let Some(&is_ptr) = typedef_ptrs.get(&name) {
+        return Some(is_ptr);
+    }
+    if is_winapi_ptr_typedef(&name) {
+        return Some(true);
+    }
+    winapi_int_type(&name, options.target.pointer_width).map(|_| false)
+}
+
+/// Find which type aliases, including in inline modules, are pointers or
+/// numbers, so that casts to them can be translated. `DECLARE_HANDLE!`
+/// handles are pointers too.
+///
+/// Aliases of aliases are followed when the alias comes first.
+fn typedef_ptrs(items: &[Item], options: &Options) -> HashMap<String, bool> {
+    fn find(items: &[Item], options: &Options, kinds: &mut HashMap<String, bool>) {
+        for item in items {
+            match item {
+                Item::Type(t) if t.generics.params.is_empty() => {
+                    if let Some(is_ptr) = is_ptr_type(&t.ty, kinds, options) {
+                        kinds.insert(t.ident.to_string(), is_ptr);
+                    }
+                }
+                Item::Macro(m) if m.mac.path.is_ident("DECLARE_HANDLE") => {
+                    if let Ok(d) = syn::parse2::<DeclareHandleMacro>(m.mac.tokens.clone()) {
+                        kinds.insert(d.handle.to_string(), true);
+                    }
+                }
+                Item::Mod(ItemMod {
+                    content: Some((_, items)),
+                    ..
+                }) => find(items, options, kinds),
+                _ => (),
+            }
+        }
+    }
+    let mut kinds = HashMap::new();
+    find(items, options, &mut kinds);
+    kinds
+}
+
+/// Find the constants declared with a named type, including in inline
+/// modules, so that casts of them can be translated.
+fn const_types(items: &[Item]) -> HashMap<String, String> {
+    let mut types = HashMap::new();
+    for item in items {
+        match item {
+            Item::Const(c) => {
+                if let Type::Path(TypePath { qself: None, path }) = &*c.ty {
+                    types.extend(path_as_single_ident(path).map(|ty| (c.ident.to_string(), ty)));
+                }
+            }
+            Item::Mod(ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => types.extend(const_types(items)),
+            _ => (),
+        }
+    }
+    types
+}
+
+/// Find the tuple structs defined by `STRUCT!`, including in inline
+/// modules, so that calls constructing them can be translated.
+fn tuple_structs(items: &[Item]) -> HashMap<String, TupleStruct> {
+    let mut structs = HashMap::new();
+    for item in items {
+        match item {
+            Item::Macro(m) if m.mac.path.is_ident("STRUCT") => {
+                let s = match syn::parse2::<ItemStruct>(m.mac.tokens.clone()) {
+                    Ok(s) => s,
+                    Err(_) => continue,
+                };
+                if let Fields::Unnamed(fields) = &s.fields {
+                    let fields = fields.unnamed.iter().enumerate();
+                    let kept = fields.filter(|(_, f)| !is_phantom_data(&f.ty));
+                    let tuple = TupleStruct {
+                        transparent: struct_repr(&s.attrs).transparent,
+                        fields: kept.map(|(i, _)| i).collect(),
+                    };
+                    structs.insert(s.ident.to_string(), tuple);
+                }
+            }
+            Item::Mod(ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => structs.extend(tuple_structs(items)),
+            _ => (),
+        }
+    }
+    structs
+}
+
+/// Find the struct sizes asserted by `const_assert!`, including in inline
+/// modules.
+///
+/// Like `flag_enums`, these are collected first, since the assertion follows
+/// the struct.
+fn size_asserts(items: &[Item]) -> HashMap<String, String> {
+    let mut sizes = HashMap::new();
+    for item in items {
+        match item {
+            Item::Macro(m) if m.mac.path.is_ident("const_assert") => {
+                sizes.extend(size_assert(&m.mac.tokens));
+            }
+            Item::Mod(ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => sizes.extend(size_asserts(items)),
+            _ => (),
+        }
+    }
+    sizes
+}
+
+/// Parse `const_assert!(size_of::<Name>() == N)` into the struct name and
+/// its size. The operands can be either way around.
+fn size_assert(toks: &TokenStream) -> Option<(String, String)> {
+    fn size_of_ident(e: &Expr) -> Option<String> {
+        let call = match e {
+            Expr::Call(call) if call.args.is_empty() => call,
+            _ => return None,
+        };
+        let segment = match &*call.func {
+            Expr::Path(p) => p.path.segments.last()?,
+            _ => return None,
+        };
+        if segment.ident != "size_of" {
+            return None;
+        }
+        match &segment.arguments {
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+                GenericArgument::Type(Type::Path(TypePath { path, qself: None })) => {
+                    path_as_single_ident(path)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+    fn int_lit(e: &Expr) -> Option<String> {
+        match e {
+            Expr::Lit(ExprLit { lit: Lit::Int(i), .. }) => Some(i.base10_digits().to_string()),
+            _ => None,
+        }
+    }
+    match syn::parse2::<Expr>(toks.clone()).ok()? {
+        Expr::Binary(b) if matches!(b.op, BinOp::Eq(_)) => {
+            match (size_of_ident(&b.left), int_lit(&b.right)) {
+                (Some(name), Some(size)) => Some((name, size)),
+                _ => Some((size_of_ident(&b.right)?, int_lit(&b.left)?)),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Translate an inherent `impl` into free functions named `Type_method`,
+/// and associated constants named `Type_NAME`.
+///
+/// Zig can't add declarations to a struct defined elsewhere, so these can't
+/// be namespaced under the type, except for the constants of a struct or
+/// union declared alongside, which `namespaced_consts` puts inside it. The
+/// methods' bodies can't be translated either, so each panics and is
+/// reported with a diagnostic.
+fn impl_to_zig(imp: &ItemImpl, cx: &mut Cx, out: &mut dyn Write) -> Result<(), Error> {
+    if imp.trait_.is_some() {
+        return Err(Error::Unhandled("trait impl".into()));
+    }
+    if !imp.generics.params.is_empty() {
+        return Err(Error::Nyi);
+    }
+    let self_ty = impl_self_ty(imp).ok_or(Error::Nyi)?;
+    cx.self_ty = Some(self_ty.clone());
+    let result = impl_items_to_zig(imp, &self_ty, cx, out);
+    cx.self_ty = None;
+    result
+}
+
+/// The name of the type an `impl` is for, if it's a plain name.
+fn impl_self_ty(imp: &ItemImpl) -> Option<String> {
+    match &*imp.self_ty {
+        Type::Path(TypePath { path, qself: None }) => path_as_single_ident(path),
+        _ => None,
+    }
+}
+
+fn impl_items_to_zig(
+    imp: &ItemImpl,
+    self_ty: &str,
+    cx: &mut Cx,
+    out: &mut dyn Write,
+) -> Result<(), Error> {
+    let mut decls = Decls::default();
+    for item in &imp.items {
+        let attrs = match item {
+            ImplItem::Method(m) => &m.attrs[..],
+            ImplItem::Const(c) => &c.attrs,
+            _ => &[],
+        };
+        let mut buf = Vec::new();
+        if !cfg_to_zig(attrs, cx, "", &mut buf)? {
+            continue;
+        }
+        let m = match item {
+            ImplItem::Method(m) => m,
+            // Already translated inside the type.
+            ImplItem::Const(_) if cx.assoc_consts.contains_key(self_ty) => continue,
+            ImplItem::Const(c) => {
+                let zig_name = format!("{}_{}", self_ty, c.ident);
+                if assoc_const_to_zig(c, self_ty, &zig_name, cx, &mut buf)? {
+                    decls.push(&buf);
+                }
+                continue;
+            }
+            _ => {
+                cx.diagnose("impl item", None, "Unhandled impl item", imp.impl_token.span);
+                continue;
+            }
+        };
+        let name = format!("{}::{}", self_ty, m.sig.ident);
+        let span = m.sig.ident.span();
+        match method_to_zig(m, self_ty, cx, &mut buf) {
+            Ok(()) => {
+                decls.push(&buf);
+                cx.diagnose("method", Some(name), "Body not translated", span);
+            }
+            Err(error @ Error::Unhandled(_)) | Err(error @ Error::Nyi) => {
+                cx.diagnose("method", Some(name), &error.to_string(), span);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+    out.write_all(&decls.into_inner())?;
+    Ok(())
+}
+
+/// Translate an associated constant of `self_ty` as `zig_name`, returning
+/// whether it could be, or else recording a diagnostic.
+fn assoc_const_to_zig(
+    c: &ImplItemConst,
+    self_ty: &str,
+    zig_name: &str,
+    cx: &Cx,
+    out: &mut dyn Write,
+) -> Result<bool, Error> {
+    match const_decl_to_zig(&c.attrs, &c.vis, zig_name, &c.ty, &c.expr, cx, out) {
+        Ok(()) => Ok(true),
+        Err(error @ Error::Unhandled(_)) | Err(error @ Error::Nyi) => {
+            let name = format!("{}::{}", self_ty, c.ident);
+            cx.diagnose("associated const", Some(name), &error.to_string(), c.ident.span());
+            Ok(false)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Translate the associated constants of the structs and unions declared
+/// among `items`, by type name, indented to go inside the type.
+///
+/// These are translated before the items, since an `impl` follows the type
+/// it's for. Every type with an inherent `impl` gets an entry, so that the
+/// `impl` itself leaves its constants out.
+fn namespaced_consts(items: &[Item], cx: &mut Cx) -> Result<HashMap<String, Vec<u8>>, Error> {
+    let types = struct_types(items);
+    let mut consts: HashMap<String, Decls> = HashMap::new();
+    for item in items {
+        let imp = match item {
+            Item::Impl(imp) if imp.trait_.is_none() && imp.generics.params.is_empty() => imp,
+            _ => continue,
+        };
+        let self_ty = match impl_self_ty(imp) {
+            Some(self_ty) if types.contains(&self_ty) => self_ty,
+            _ => continue,
+        };
+        let decls = consts.entry(self_ty.clone()).or_default();
+        let mut impl_cfg = Vec::new();
+        if !cfg_to_zig(&imp.attrs, cx, "", &mut impl_cfg)? {
+            continue;
+        }
+        cx.self_ty = Some(self_ty.clone());
+        for item in &imp.items {
+            let c = match item {
+                ImplItem::Const(c) => c,
+                _ => continue,
+            };
+            let mut buf = impl_cfg.clone();
+            if !cfg_to_zig(&c.attrs, cx, "", &mut buf)? {
+                continue;
+            }
+            if assoc_const_to_zig(c, &self_ty, &c.ident.to_string(), cx, &mut buf)? {
+                decls.push(&buf);
+            }
+        }
+        cx.self_ty = None;
+    }
+    let mut indented = HashMap::new();
+    for (name, decls) in consts {
+        let mut out = Vec::new();
+        for line in String::from_utf8(decls.into_inner()).unwrap().lines() {
+            if line.is_empty() {
+                writeln!(out)?;
+            } else {
+                writeln!(out, "    {}", line)?;
+            }
+        }
+        indented.insert(name, out);
+    }
+    Ok(indented)
+}
+
+/// The structs and unions declared directly among `items` that can hold
+/// declarations, which is all but the transparent newtypes.
+fn struct_types(items: &[Item]) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for item in items {
+        match item {
+            Item::Macro(m) if m.mac.path.is_ident("STRUCT") => {
+                let s = take_inline_unions(&m.mac.tokens)
+                    .ok()
+                    .and_then(|(toks, _)| syn::parse2::<ItemStruct>(toks).ok());
+                if let Some(s) = s {
+                    if !struct_repr(&s.attrs).transparent {
+                        names.insert(s.ident.to_string());
+                    }
+                }
+            }
+            Item::Macro(m) if m.mac.path.is_ident("UNION") => {
+                if let Ok(u) = syn::parse2::<UnionMacro>(m.mac.tokens.clone()) {
+                    names.insert(u.ident.to_string());
+                }
+            }
+            Item::Macro(m) if m.mac.path.is_ident("RIDL") => {
+                if let Ok(r) = syn::parse2::<RidlMacro>(m.mac.tokens.clone()) {
+                    names.insert(r.ident.to_string());
+                }
+            }
+            Item::Union(u) if !struct_repr(&u.attrs).transparent => {
+                names.insert(u.ident.to_string());
+            }
+            _ => (),
+        }
+    }
+    names
+}
+
+fn method_to_zig(
+    m: &ImplItemMethod,
+    self_ty: &str,
+    cx: &Cx,
+    out: &mut dyn Write,
+) -> Result<(), Error> {
+    if !m.sig.generics.params.is_empty() || m.sig.variadic.is_some() {
+        return Err(Error::Nyi);
+    }
+    fn_docs_to_zig(&m.attrs, out)?;
+    let vis = vis_to_zig(&m.vis);
+    writeln!(out, "{}fn {}_{}(", vis, self_ty, m.sig.ident)?;
+    // Every parameter is discarded, since Zig rejects unused ones.
+    let mut discards = Vec::new();
+    for (i, arg) in m.sig.inputs.iter().enumerate() {
+        match arg {
+            FnArg::Receiver(r) => {
+                let ty = match (&r.reference, &r.mutability) {
+                    (Some(_), Some(_)) => format!("*{}", self_ty),
+                    (Some(_), None) => format!("*const {}", self_ty),
+                    (None, _) => self_ty.to_string(),
+                };
+                writeln!(out, "    self: {},", ty)?;
+                discards.push("self".to_string());
+            }
+            FnArg::Typed(t) => {
+                let name = arg_name(&t.pat, i);
+                if name != "_" {
+                    discards.push(name);
+                }
+                fn_arg_to_zig(arg, i, cx, out)?;
+            }
+        }
+    }
+    writeln!(out, ") {} {{", ret_ty_to_zig(&m.sig.output, cx)?)?;
+    for discard in &discards {
+        writeln!(out, "    _ = {};", discard)?;
+    }
+    writeln!(out, "    @panic(\"unimplemented\");")?;
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+/// The expression a function body evaluates to, if that's all it does.
+fn body_expr(block: &syn::Block) -> Option<&Expr> {
+    match block.stmts.as_slice() {
+        [Stmt::Expr(e)] => match e {
+            Expr::Return(r) => r.expr.as_deref(),
+            _ => Some(e),
+        },
+        [Stmt::Semi(Expr::Return(r), _)] => r.expr.as_deref(),
+        _ => None,
+    }
+}
+
+/// Whether `name` is used as an identifier in the Zig source `zig`.
+fn mentions_ident(zig: &str, name: &str) -> bool {
+    let name = name.strip_prefix("@\"").and_then(|n| n.strip_suffix('"')).unwrap_or(name);
+    zig.split(|c: char| !(c.is_alphanumeric() || c == '_')).any(|word| word == name)
+}
+
+/// Translate a free function, like the small `const fn` helpers for
+/// packing words.
+///
+/// A body that's a single expression is translated. Anything more is left as
+/// a stub that panics, and reported with a diagnostic.
+fn fn_to_zig(f: &ItemFn, cx: &Cx, out: &mut dyn Write) -> Result<(), Error> {
+    if !f.sig.generics.params.is_empty() || f.sig.variadic.is_some() {
+        return Err(Error::Nyi);
+    }
+    let callconv = match &f.sig.abi {
+        Some(abi) => format!(" callconv({})", abi_to_callconv(Some(abi), cx)?),
+        None => String::new(),
+    };
+    let ret = ret_ty_to_zig(&f.sig.output, cx)?;
+    // The parameters shadow the constants.
+    let mut params = cx.const_types.clone();
+    for arg in &f.sig.inputs {
+        if let FnArg::Typed(t) = arg {
+            if let (Pat::Ident(p), Type::Path(TypePath { qself: None, path })) = (&*t.pat, &*t.ty) {
+                params.extend(path_as_single_ident(path).map(|ty| (p.ident.to_string(), ty)));
+            }
+        }
+    }
+    *cx.fn_params.borrow_mut() = Some(params);
+    let body = body_expr(&f.block).and_then(|e| expr_to_zig(e, cx).ok());
+    *cx.fn_params.borrow_mut() = None;
+    fn_docs_to_zig(&f.attrs, out)?;
+    writeln!(out, "{}fn {}(", vis_to_zig(&f.vis), zig_ident(&f.sig.ident.to_string()))?;
+    // Zig rejects unused parameters, so those are discarded.
+    let mut discards = Vec::new();
+    for (i, arg) in f.sig.inputs.iter().enumerate() {
+        if let FnArg::Typed(t) = arg {
+            let name = arg_name(&t.pat, i);
+            let used = body.as_ref().is_some_and(|body| mentions_ident(body, &name));
+            if name != "_" && !used {
+                discards.push(name);
+            }
+        }
+        fn_arg_to_zig(arg, i, cx, out)?;
+    }
+    writeln!(out, "){} {} {{", callconv, ret)?;
+    for discard in &discards {
+        writeln!(out, "    _ = {};", discard)?;
+    }
+    match &body {
+        Some(body) if ret == "void" => writeln!(out, "    {};", body)?,
+        Some(body) => writeln!(out, "    return {};", body)?,
+        None => writeln!(out, "    @panic(\"unimplemented\");")?,
+    }
+    writeln!(out, "}}")?;
+    if body.is_none() {
+        let name = f.sig.ident.to_string();
+        cx.diagnose("fn", Some(name), "Body not translated", f.sig.ident.span());
+    }
+    Ok(())
+}
+
+fn item_to_zig(item: &Item, cx: &mut Cx, out: &mut dyn Write) -> Result<(), Error> {
+    if !cfg_to_zig(item_attrs(item), cx, "", out)? {
+        return Ok(());
+    }
+    match item {
+        Item::Use(u) => use_to_zig(u, cx, out)?,
+        Item::Type(t) => type_to_zig(t, cx, out)?,
+        Item::Const(c) => const_to_zig(c, cx, out)?,
+        Item::Static(st) => static_to_zig(st, cx, out)?,
+        Item::ForeignMod(fm) => foreign_mod_to_zig(fm, cx, out)?,
+        Item::Macro(m) => macro_to_zig(m, cx, out)?,
+        Item::Fn(f) => fn_to_zig(f, cx, out)?,
+        Item::Enum(e) => enum_to_zig(e, cx, out)?,
+        Item::Mod(m) => mod_to_zig(m, cx, out)?,
+        Item::Impl(i) => impl_to_zig(i, cx, out)?,
+        Item::Union(u) => union_to_zig(u, cx, out)?,
+        _ => return Err(Error::Nyi),
+    }
+    Ok(())
+}
+
+/// Translate a module into a Zig namespace, or an `@import` of its own file.
+fn mod_to_zig(m: &ItemMod, cx: &mut Cx, out: &mut dyn Write) -> Result<(), Error> {
+    docs_to_zig(&m.attrs, "", out)?;
+    let vis = vis_to_zig(&m.vis);
+    let items = match &m.content {
+        Some((_, items)) => items,
+        None => {
+            let name = zig_ident(&m.ident.to_string());
+            writeln!(out, "{}const {} = @import(\"{}.zig\");", vis, name, m.ident)?;
+            return Ok(());
+        }
+    };
+    cx.mod_depth += 1;
+    let body = items_to_zig(items, cx);
+    cx.mod_depth -= 1;
+    writeln!(out, "{}const {} = struct {{", vis, zig_ident(&m.ident.to_string()))?;
+    for line in String::from_utf8(body?).unwrap().lines() {
+        if line.is_empty() {
+            writeln!(out)?;
+        } else {
+            writeln!(out, "    {}", line)?;
+        }
+    }
+    writeln!(out, "}};")?;
+    Ok(())
+}
+
+fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Const(i) => &i.attrs,
+        Item::Enum(i) => &i.attrs,
+        Item::ExternCrate(i) => &i.attrs,
+        Item::Fn(i) => &i.attrs,
+        Item::ForeignMod(i) => &i.attrs,
+        Item::Impl(i) => &i.attrs,
+        Item::Macro(i) => &i.attrs,
+        Item::Macro2(i) => &i.attrs,
+        Item::Mod(i) => &i.attrs,
+        Item::Static(i) => &i.attrs,
+        Item::Struct(i) => &i.attrs,
+        Item::Trait(i) => &i.attrs,
+        Item::TraitAlias(i) => &i.attrs,
+        Item::Type(i) => &i.attrs,
+        Item::Union(i) => &i.attrs,
+        Item::Use(i) => &i.attrs,
+        _ => &[],
+    }
+}
+
+/// The kind, name, and location of an item, for diagnostics.
+fn item_info(item: &Item) -> (&'static str, Option<String>, Span) {
+    fn named(kind: &'static str, ident: &Ident) -> (&'static str, Option<String>, Span) {
+        (kind, Some(ident.to_string()), ident.span())
+    }
+    match item {
+        Item::Const(c) => named("const", &c.ident),
+        Item::Enum(e) => named("enum", &e.ident),
+        Item::ExternCrate(e) => named("extern crate", &e.ident),
+        Item::Fn(f) => named("fn", &f.sig.ident),
+        Item::ForeignMod(fm) => ("extern block", None, fm.abi.extern_token.span),
+        Item::Impl(i) => ("impl", None, i.impl_token.span),
+        Item::Macro(m) => {
+            let name = path_as_single_ident(&m.mac.path).map(|id| format!("{}!", id));
+            let span = match m.mac.path.segments.first() {
+                Some(seg) => seg.ident.span(),
+                None => m.mac.bang_token.span,
+            };
+            ("macro", name, span)
+        }
+        Item::Macro2(m) => named("macro", &m.ident),
+        Item::Mod(m) => named("mod", &m.ident),
+        Item::Static(s) => named("static", &s.ident),
+        Item::Struct(s) => named("struct", &s.ident),
+        Item::Trait(t) => named("trait", &t.ident),
+        Item::TraitAlias(t) => named("trait alias", &t.ident),
+        Item::Type(t) => named("type", &t.ident),
+        Item::Union(u) => named("union", &u.ident),
+        Item::Use(u) => ("use", None, u.use_token.span),
+        _ => ("item", None, Span::call_site()),
+    }
+}
+
+/// Translate an item, recording a diagnostic instead if it can't be handled.
+///
+/// Each item is translated into a scratch buffer first, so a failure partway
+/// through doesn't leave a fragment in the output.
+fn wrap_item_to_zig(item: &Item, cx: &mut Cx, out: &mut dyn Write) -> Result<(), Error> {
+    let mut buf = Vec::new();
+    let message = match item_to_zig(item, cx, &mut buf) {
+        Ok(()) => {
+            out.write_all(&buf)?;
+            None
+        }
+        Err(error @ Error::Unhandled(_))
+        | Err(error @ Error::Nyi)
+        | Err(error @ Error::Macro(..)) => Some(error.to_string()),
+        Err(error) => return Err(error),
+    };
+    let (kind, name, span) = item_info(item);
+    if cx.mod_depth == 0 {
+        let start = span.start();
+        cx.items.push(ItemReport {
+            kind,
+            name: name.clone(),
+            translated: message.is_none(),
+            line: start.line,
+            column: start.column + 1,
+        });
+    }
+    if let Some(message) = message {
+        cx.diagnose(kind, name, &message, span);
+    }
+    Ok(())
+}
+
+/// Translate a file's or an inline module's items, as declarations.
+fn items_to_zig(items: &[Item], cx: &mut Cx) -> Result<Vec<u8>, Error> {
+    let consts = namespaced_consts(items, cx)?;
+    let outer = std::mem::replace(&mut cx.assoc_consts, consts);
+    let mut decls = Decls::default();
+    for item in items {
+        let mut decl = Vec::new();
+        wrap_item_to_zig(item, cx, &mut decl)?;
+        decls.push(&decl);
+    }
+    cx.assoc_consts = outer;
+    Ok(decls.into_inner())
+}
+
+/// Report the types referred to but never declared in the translated `body`,
+/// once each.
+///
+/// Zig resolves names regardless of order, so a type used before its
+/// definition, or structs pointing to each other, are fine. This only finds
+/// names that are missing altogether, because they were never imported or
+/// their definition couldn't be translated. Every declaration counts, at
+/// any depth, so a name only visible in another inline module is missed.
+fn check_undeclared(body: &[u8], cx: &Cx) {
+    let mut declared = HashSet::new();
+    for line in String::from_utf8_lossy(body).lines() {
+        let line = line.trim_start();
+        let line = line.strip_prefix("pub ").unwrap_or(line);
+        let rest = line.strip_prefix("const ").or_else(|| line.strip_prefix("var "));
+        if let Some(rest) = rest {
+            declared.extend(rest.split([' ', ':']).next().map(str::to_owned));
+        }
+        // Generic type aliases' parameters.
+        for param in line.split("comptime ").skip(1) {
+            declared.extend(param.split(':').next().map(str::to_owned));
+        }
+    }
+    let mut reported = HashSet::new();
+    for (name, span) in cx.type_refs.borrow().iter() {
+        if !declared.contains(name) && reported.insert(name) {
+            let message = "Undeclared type, not defined or imported in this file";
+            cx.note("type reference", Some(name.clone()), message, *span);
+        }
+    }
+}
+
+/// The module a source file is, like `winuser` for `um/winuser.rs` or `um`
+/// for `um/mod.rs`.
+pub fn module_name(filepath: &Path) -> Option<String> {
+    let stem = filepath.file_stem()?;
+    let name = if stem == "mod" {
+        filepath.parent()?.file_name()?
+    } else {
+        stem
+    };
+    name.to_str().map(str::to_owned)
+}
+
+fn convert(
+    code: String,
+    filepath: Option<PathBuf>,
+    options: &Options,
+) -> Result<Translation, Error> {
+    let module = filepath.as_deref().and_then(module_name);
+    let syntax = syn::parse_file(&code).map_err({
+        |error| Error::ParseFile {
+            error,
+            filepath,
+            source_code: code,
+        }
+    })?;
+    let mut cx = Cx {
+        options: options.clone(),
+        module,
+        toplevel_imports: Default::default(),
+        self_ty: None,
+        mod_depth: 0,
+        flag_enums: flag_enums(&syntax.items),
+        size_asserts: size_asserts(&syntax.items),
+        tuple_structs: tuple_structs(&syntax.items),
+        typedef_ptrs: typedef_ptrs(&syntax.items, options),
+        assoc_consts: Default::default(),
+        fn_params: Default::default(),
+        init_ty: Default::default(),
+        const_types: const_types(&syntax.items),
+        diagnostics: Default::default(),
+        type_refs: Default::default(),
+        glob_import: false,
+        items: Vec::new(),
+    };
+    let body = items_to_zig(&syntax.items, &mut cx)?;
+    if cx.options.check_undeclared && !cx.glob_import {
+        check_undeclared(&body, &cx);
+    }
+    let mut out = Vec::new();
+    // Module docs (`//!`) become Zig's container docs, which go first. The
+    // file's other inner attributes, like `#![allow]`, mean nothing to Zig.
+    let inner_docs = syntax.attrs.iter().any(|attr| path_equals(&attr.path, "doc"));
+    if inner_docs {
+        doc_comments_to_zig(&syntax.attrs, "//!", "", &mut out)?;
+        writeln!(out)?;
+    }
+    for (name, file) in &cx.toplevel_imports {
+        writeln!(out, "const {} = @import(\"{}\");", zig_ident(name), file)?;
+    }
+    if !cx.toplevel_imports.is_empty() {
+        writeln!(out)?;
+    }
+    out.extend(body);
+    Ok(Translation {
+        zig: String::from_utf8(out).unwrap(),
+        diagnostics: cx.diagnostics.into_inner(),
+        items: cx.items,
+        imports: cx.toplevel_imports.into_values().collect(),
+    })
+}
+
+/// Translate Rust source text into Zig, with default options.
+pub fn convert_str(source: &str) -> Result<Translation, Error> {
+    convert_str_with_options(source, &Options::default())
+}
+
+/// Translate Rust source text into Zig.
+pub fn convert_str_with_options(source: &str, options: &Options) -> Result<Translation, Error> {
+    convert(source.to_owned(), None, options)
+}
+
+/// Translate the Rust source file at `filepath` into Zig, with default options.
+pub fn convert_file(filepath: &Path) -> Result<Translation, Error> {
+    convert_file_with_options(filepath, &Options::default())
+}
+
+/// Translate the Rust source file at `filepath` into Zig.
+pub fn convert_file_with_options(filepath: &Path, options: &Options) -> Result<Translation, Error> {
+    let code = fs::read_to_string(filepath).map_err(Error::ReadFile)?;
+    convert(code, Some(filepath.to_owned()), options)
+}
+
+/// Write a `build.zig` declaring each translated file as a module, so the
+/// output can be used as a package.
+///
+/// The modules' paths are taken to be from the root of the crate, as their
+/// imports are, so `um/winuser.zig` importing `shared/minwindef.zig` depends
+/// on the module at that path, and `um.zig` can be `um/mod.zig`. Zig looks
+/// up an `@import` among a module's dependencies before trying it as a path,
+/// so each dependency is added under the name it's imported by. Imports of
+/// files that weren't translated are left for Zig to resolve by path.
+pub fn build_zig(modules: &[BuildModule]) -> String {
+    let mut deps = Vec::new();
+    for (i, m) in modules.iter().enumerate() {
+        for import in &m.imports {
+            let mod_rs = import.strip_suffix(".zig").map(|stem| format!("{}/mod.zig", stem));
+            let found = modules
+                .iter()
+                .position(|dep| &dep.path == import || Some(&dep.path) == mod_rs.as_ref());
+            match found {
+                Some(j) if j != i => deps.push((i, j, import)),
+                _ => (),
+            }
+        }
+    }
+    let mut out = String::new();
+    out.push_str("const std = @import(\"std\");\n\n");
+    out.push_str("pub fn build(b: *std.Build) void {\n");
+    // Modules can import each other in cycles, so all are declared before
+    // any dependencies are added. Zig rejects unused locals, so only those
+    // with dependencies either way are named.
+    for (i, m) in modules.iter().enumerate() {
+        let local = if deps.iter().any(|&(a, b, _)| a == i || b == i) {
+            format!("const {}", zig_ident(&m.name))
+        } else {
+            "_".to_string()
+        };
+        let source = format!(".{{ .source_file = .{{ .path = \"{}\" }} }}", m.path);
+        out.push_str(&format!("    {} = b.addModule(\"{}\", {});\n", local, m.name, source));
+    }
+    for (i, j, import) in deps {
+        let (m, dep) = (zig_ident(&modules[i].name), zig_ident(&modules[j].name));
+        let put = format!("{}.dependencies.put(\"{}\", {})", m, import, dep);
+        out.push_str(&format!("    {} catch @panic(\"OOM\");\n", put));
+    }
+    out.push_str("}\n");
+    out
+}
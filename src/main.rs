@@ -1,50 +1,16 @@
-//!
+//! A script to translate winapi-rs definitions into Zig.
 
 use std::collections::HashSet;
 use std::env;
-use std::fmt::{self, Display};
 use std::fs;
 use std::io::{self, Write};
-use std::path::PathBuf;
-use std::process;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
 
-use proc_macro2::{TokenStream, TokenTree};
-use syn::{
-    Attribute, Expr, FnArg, ForeignItem, Item, ItemConst, ItemFn, ItemForeignMod, ItemMacro, ItemStruct,
-    ItemType, ItemUse, Lit, Pat, PathArguments, ReturnType, Type, TypePath, UseTree, Visibility,
-    UnOp, TypeBareFn, BareFnArg,
+use winapi_zig::{
+    build_zig, convert_file_with_options, module_name, parse_link_lib_map, parse_type_map,
+    BuildModule, Error, Options, Target, Translation,
 };
-#[allow(unused)]
-enum Error {
-    IncorrectUsage,
-    ReadFile(io::Error),
-    ParseFile {
-        error: syn::Error,
-        filepath: PathBuf,
-        source_code: String,
-    },
-    Unhandled(String),
-    Nyi,
-}
-
-struct Cx {
-    link_name: String,
-    toplevel_imports: HashSet<String>,
-}
-
-impl Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use self::Error::*;
-
-        match self {
-            IncorrectUsage => write!(f, "Usage: dump-syntax path/to/filename.rs"),
-            ReadFile(error) => write!(f, "Unable to read file: {}", error),
-            ParseFile { error, .. } => write!(f, "Unable to parse file: {}", error),
-            Unhandled(item_name) => write!(f, "Unhandled item {}", item_name),
-            Nyi => write!(f, "Not yet implemented"),
-        }
-    }
-}
 
 fn main() {
     if let Err(error) = try_main() {
@@ -53,342 +19,293 @@ fn main() {
     }
 }
 
-fn path_as_single_ident(path: &syn::Path) -> Option<String> {
-    if path.segments.len() == 1 {
-        let seg = &path.segments[0];
-        if seg.arguments == PathArguments::None {
-            return Some(seg.ident.to_string());
-        }
-    }
-    None
+/// Settings from the command line.
+struct Args {
+    filepaths: Vec<PathBuf>,
+    output: Option<PathBuf>,
+    options: Options,
+    /// Run the output through `zig fmt`.
+    fmt: bool,
+    /// Start each output with a generated-file banner.
+    header: bool,
+    /// Only summarize what can't be translated, with no Zig output.
+    list_unhandled: bool,
+    /// Write a `build.zig` for the outputs too.
+    emit_build_zig: bool,
+    /// Log what became of each item.
+    verbose: bool,
+    /// Fail if any item isn't translated.
+    strict: bool,
 }
 
-fn path_equals(path: &syn::Path, id: &str) -> bool {
-    if path.segments.len() == 1 {
-        let seg = &path.segments[0];
-        if seg.arguments == PathArguments::None {
-            return seg.ident == id;
-        }
-    }
-    false
-}
+fn parse_args() -> Result<Args, Error> {
+    let mut args = env::args_os();
+    let _ = args.next(); // executable name
 
-fn ty_to_zig(ty: &Type) -> Result<String, Error> {
-    match ty {
-        Type::Path(TypePath { path, .. }) => {
-            if path.segments.len() == 1 {
-                let seg = &path.segments[0];
-                if seg.arguments == PathArguments::None {
-                    let mut ident = seg.ident.to_string();
-                    // Zig doesn't have c char types.
-                    match ident.as_str() {
-                        "c_void" => ident = "std::os::raw::c_void".into(),
-                        "c_uchar" => ident = "u8".into(),
-                        "c_char" | "c_schar" => ident = "i8".into(),
-                        "c_float" => ident = "f32".into(),
-                        "c_double" => ident = "f64".into(),
-                        "wchar_t" => ident = "u16".into(),
-                        "__uint64" => ident = "u64".into(),
-                        "__int64" => ident = "i64".into(),
-                        _ => (),
-                    }
-                    return Ok(ident);
-                }
-            }
-        }
-        Type::Ptr(p) => {
-            let mut_str = if p.const_token.is_some() {
-                "const "
-            } else {
-                ""
-            };
-            return Ok(format!("?*{}{}", mut_str, ty_to_zig(&p.elem)?));
+    let mut parsed = Args {
+        filepaths: Vec::new(),
+        output: None,
+        options: Options::default(),
+        fmt: false,
+        header: true,
+        list_unhandled: false,
+        emit_build_zig: false,
+        verbose: false,
+        strict: false,
+    };
+    while let Some(arg) = args.next() {
+        if arg == "-o" || arg == "--output" {
+            parsed.output = Some(PathBuf::from(args.next().ok_or(Error::IncorrectUsage)?));
+        } else if arg == "--default-lib" {
+            let lib = args.next().ok_or(Error::IncorrectUsage)?;
+            parsed.options.default_lib = lib.into_string().map_err(|_| Error::IncorrectUsage)?;
+        } else if arg == "--glob-as-usingnamespace" {
+            parsed.options.glob_as_usingnamespace = true;
+        } else if arg == "--target" {
+            let target = args.next().ok_or(Error::IncorrectUsage)?;
+            let target = target.into_string().map_err(|_| Error::IncorrectUsage)?;
+            parsed.options.target = Target::parse(&target).ok_or(Error::UnknownTarget(target))?;
+        } else if arg == "--type-map" {
+            let path = PathBuf::from(args.next().ok_or(Error::IncorrectUsage)?);
+            let source = fs::read_to_string(&path).map_err(Error::ReadFile)?;
+            parsed.options.type_map = parse_type_map(&source)?;
+        } else if arg == "--link-lib-map" {
+            let path = PathBuf::from(args.next().ok_or(Error::IncorrectUsage)?);
+            let source = fs::read_to_string(&path).map_err(Error::ReadFile)?;
+            parsed.options.link_libs = parse_link_lib_map(&source)?;
+        } else if arg == "--module-dir" {
+            let dir = args.next().ok_or(Error::IncorrectUsage)?;
+            let dir = dir.into_string().map_err(|_| Error::IncorrectUsage)?;
+            parsed.options.module_dirs.insert(dir);
+        } else if arg == "--fmt" {
+            parsed.fmt = true;
+        } else if arg == "--list-unhandled" {
+            parsed.list_unhandled = true;
+        } else if arg == "--check-undeclared" {
+            parsed.options.check_undeclared = true;
+        } else if arg == "--verbose" {
+            parsed.verbose = true;
+        } else if arg == "--c-strings" {
+            parsed.options.c_strings = true;
+        } else if arg == "--strict" {
+            parsed.strict = true;
+        } else if arg == "--emit-build-zig" {
+            parsed.emit_build_zig = true;
+        } else if arg == "--no-header" {
+            parsed.header = false;
+        } else {
+            parsed.filepaths.push(PathBuf::from(arg));
         }
-        _ => (),
-    }
-    Err(Error::Nyi)
-}
-
-fn ret_ty_to_zig(r: &ReturnType) -> Result<String, Error> {
-    match r {
-        ReturnType::Type(_, t) => ty_to_zig(t),
-        ReturnType::Default => Ok("".to_string()),
     }
+    Ok(parsed)
 }
 
-fn vis_to_zig(v: &Visibility) -> &str {
-    if matches!(v, Visibility::Public(_)) {
-        "pub "
-    } else {
-        ""
-    }
-}
-
-fn expr_to_zig(e: &Expr) -> String {
-    match e {
-        Expr::Lit(l) => match &l.lit {
-            Lit::Int(i) => return i.to_string(),
-            _ => (),
-        },
-        Expr::Unary(u) => {
-            if let UnOp::Neg(_) = u.op {
-                // Risk of precedence issue here.
-                return format!("-{}", expr_to_zig(&u.expr));
-            }
-        }
-        Expr::Path(p) => {
-            if let Some(ident) = path_as_single_ident(&p.path) {
-                return ident;
-            }
-        }
-        _ => {
-            println!("// {:?}", e);
+/// Format Zig source with `zig fmt`, warning and returning `None` if that
+/// isn't possible.
+fn zig_fmt(source: &str) -> Option<String> {
+    let child = Command::new("zig")
+        .args(["fmt", "--stdin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(error) => {
+            eprintln!("warning: unable to run `zig fmt`: {}", error);
+            return None;
         }
+    };
+    // `zig fmt` reads all of stdin before writing anything, so this can't
+    // deadlock on a full stdout pipe.
+    if let Err(error) = child.stdin.take().unwrap().write_all(source.as_bytes()) {
+        eprintln!("warning: unable to run `zig fmt`: {}", error);
     }
-    "???".into()
-}
-
-/// Determine whether we should keep this item.
-fn resolve_attrs(attrs: &[Attribute]) -> bool {
-    for attr in attrs {
-        if path_equals(&attr.path, "cfg") {
-            let mut body = attr.tokens.clone().into_iter();
-            if let Some(TokenTree::Group(g)) = body.next() {
-                let mut toks = g.stream().clone().into_iter();
-                if let Some(TokenTree::Ident(id)) = toks.next() {
-                    if id == "target_pointer_width" {
-                        toks.next();
-                        if let Some(TokenTree::Literal(l)) = toks.next() {
-                            if l.to_string() == "\"32\"" {
-                                return false;
-                            }
-                        }
-                    }
-                }
-            }
+    let output = match child.wait_with_output() {
+        Ok(output) => output,
+        Err(error) => {
+            eprintln!("warning: unable to run `zig fmt`: {}", error);
+            return None;
         }
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        eprintln!("warning: `zig fmt` failed: {}", stderr.trim());
+        return None;
     }
-    true
+    String::from_utf8(output.stdout).ok()
 }
 
-type UsePath = Vec<String>;
-
-/// Expand a use tree into individual paths.
-fn expand_use_tree(u: &UseTree) -> Result<Vec<UsePath>, Error> {
-    fn expand_rec(u: &UseTree, prefix: &[String], b: &mut Vec<UsePath>) -> Result<(), Error> {
-        match u {
-            UseTree::Path(p) => {
-                let mut path = prefix.to_owned();
-                path.push(p.ident.to_string());
-                expand_rec(&p.tree, &path, b)?;
-            }
-            UseTree::Name(n) => {
-                let mut path = prefix.to_owned();
-                path.push(n.ident.to_string());
-                b.push(path);
-            }
-            UseTree::Group(g) => {
-                for tree in &g.items {
-                    expand_rec(tree, prefix, b)?;
-                }
-            }
-            _ => return Err(Error::Nyi),
-        }
-        Ok(())
-    }
-    let mut b = Vec::new();
-    expand_rec(u, &[], &mut b)?;
-    Ok(b)
+/// Write the banner marking the output as generated from `filepath`.
+///
+/// These are line comments, so `zig fmt` leaves them alone.
+fn write_header(filepath: &Path, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "// Generated by winapi-zig from {}.", filepath.display())?;
+    writeln!(out, "// Do not edit: changes will be lost when it is regenerated.")?;
+    writeln!(out)
 }
 
-fn use_to_zig(u: &ItemUse, cx: &mut Cx) -> Result<(), Error> {
-    for path in expand_use_tree(&u.tree)? {
-        let toplevel = &path[0];
-        if toplevel != "ctypes" {
-            if !cx.toplevel_imports.contains(toplevel) {
-                println!("");
-                println!("const {} = @import(\"{}.zig\");", toplevel, toplevel);
-            }
-            cx.toplevel_imports.insert(toplevel.clone());
-            let last = path.last().unwrap();
-            let vis = vis_to_zig(&u.vis);
-            let import = path.join(".");
-            println!("{}const {} = {};", vis, last, import);
+/// Translate a single Rust source file, writing the Zig to `out`.
+fn translate_file(
+    filepath: &Path,
+    args: &Args,
+    out: &mut dyn Write,
+) -> Result<Translation, Error> {
+    let translation = convert_file_with_options(filepath, &args.options)?;
+    if args.verbose {
+        for item in &translation.items {
+            eprintln!("{}:{}", filepath.display(), item);
         }
     }
-    Ok(())
-}
-
-fn const_to_zig(c: &ItemConst) {
-    //println!("{:#?}", c);
-    let vis = vis_to_zig(&c.vis);
-    println!("{}const {} = {};", vis, c.ident, expr_to_zig(&c.expr));
-}
-
-fn type_to_zig(t: &ItemType) -> Result<(), Error> {
-    if !resolve_attrs(&t.attrs) {
-        return Ok(());
+    for diagnostic in &translation.diagnostics {
+        eprintln!("{}:{}", filepath.display(), diagnostic);
     }
-    //println!("{:#?}", t);
-    let vis = vis_to_zig(&t.vis);
-    let ident = t.ident.to_string();
-    println!("{}const {} = {};", vis, ident, ty_to_zig(&t.ty)?);
-    Ok(())
-}
-
-fn fn_arg_to_zig(arg: &FnArg) -> Result<(), Error> {
-    //println!("{:?}", arg);
-    let mut ident = String::new();
-    if let FnArg::Typed(t) = arg {
-        match t.pat.as_ref() {
-            Pat::Ident(i) => ident = i.ident.to_string(),
-            Pat::Wild(_) => ident = "_".to_string(),
-            _ => (),
-        }
-        println!("    {}: {},", ident, ty_to_zig(&t.ty)?);
+    let untranslated = translation.untranslated();
+    if untranslated > 0 {
+        eprintln!("{}: {} item(s) not translated", filepath.display(), untranslated);
     }
-    Ok(())
-}
-
-fn bare_fn_arg_to_zig(arg: &BareFnArg) -> Result<(), Error> {
-    if let Some((ident, _)) = &arg.name {
-        println!("    {}: {},", ident, ty_to_zig(&arg.ty)?);
-    } else {
-        println!("    {},", ty_to_zig(&arg.ty)?);
-
+    let formatted = if args.fmt { zig_fmt(&translation.zig) } else { None };
+    let zig = formatted.as_ref().unwrap_or(&translation.zig);
+    if args.header {
+        write_header(filepath, out).map_err(Error::WriteFile)?;
     }
-    Ok(())
+    out.write_all(zig.as_bytes()).map_err(Error::WriteFile)?;
+    Ok(translation)
 }
 
-fn foreign_mod_to_zig(fm: &ItemForeignMod, cx: &Cx) -> Result<(), Error> {
-    //println!("{:#?}", fm);
-    for item in &fm.items {
-        match item {
-            ForeignItem::Fn(f) => {
-                let vis = vis_to_zig(&f.vis);
-                println!("{}extern \"{}\" fn {} (", vis, cx.link_name, &f.sig.ident);
-                for arg in &f.sig.inputs {
-                    fn_arg_to_zig(arg)?;
-                }
-                println!(") callconv(.Stdcall) {};", ret_ty_to_zig(&f.sig.output)?)
+/// The deepest directory containing all of `filepaths`.
+fn common_root(filepaths: &[PathBuf]) -> PathBuf {
+    let mut root: Option<PathBuf> = None;
+    for filepath in filepaths {
+        let dir = filepath.parent().unwrap_or(Path::new(""));
+        root = Some(match root {
+            None => dir.to_owned(),
+            Some(root) => {
+                let common = root.components().zip(dir.components()).take_while(|(a, b)| a == b);
+                common.map(|(a, _)| a).collect()
             }
-            _ => println!("{:?}", item),
-        }
+        });
     }
-    Ok(())
+    root.unwrap_or_default()
 }
 
-fn struct_macro_to_zig(toks: &TokenStream) -> Result<(), Error> {
-    let s: ItemStruct = syn::parse2(toks.to_owned()).unwrap();
-    //println!("STRUCT! {:?}", s);
-    println!("pub const {} = extern struct {{", s.ident);
-    for f in &s.fields {
-        println!("    {}: {},", f.ident.as_ref().unwrap(), ty_to_zig(&f.ty)?);
+/// With `--strict`, fail if `untranslated` items were left out.
+fn check_strict(args: &Args, untranslated: usize) -> Result<(), Error> {
+    if args.strict && untranslated > 0 {
+        return Err(Error::Untranslated(untranslated));
     }
-    println!("}};");
     Ok(())
 }
 
-fn declare_handle_to_zig(toks: &TokenStream) -> Result<(), Error> {
-    let mut tok_iter = toks.clone().into_iter();
-    let handle_id = tok_iter.next().ok_or(Error::Nyi)?;
-    // Skip comma. We *should* check, but meh.
-    tok_iter.next();
-    let opaque_id = tok_iter.next().ok_or(Error::Nyi)?;
-    if let (TokenTree::Ident(h), TokenTree::Ident(o)) = (handle_id, opaque_id) {
-        println!("pub const {} = @Type(.Opaque);", o.to_string());
-        println!("pub const {} = ?*{};", h.to_string(), o.to_string());
+/// Print a summary of the items each input leaves untranslated.
+fn list_unhandled(args: &Args) -> Result<(), Error> {
+    if args.filepaths.is_empty() {
+        return Err(Error::IncorrectUsage);
     }
-    Ok(())
-}
-
-fn fn_macro_to_zig(toks: &TokenStream) -> Result<(), Error> {
-    let mut tok_iter = toks.clone().into_iter();
-    if let Some(TokenTree::Ident(_callconv)) = tok_iter.next() {
-        let ident = tok_iter.next().unwrap();
-        let mut inner_fn: TokenStream = "fn".parse().unwrap();
-        inner_fn.extend(tok_iter);
-        // TODO: better error
-        let bare_fn: TypeBareFn = syn::parse2(inner_fn).map_err(|_| Error::Nyi)?;
-        println!("pub const {} = fn(", ident);
-        for arg in &bare_fn.inputs {
-            bare_fn_arg_to_zig(arg)?;
-        }
-        println!(") callconv(.Stdcall) {};", ret_ty_to_zig(&bare_fn.output)?);
+    let mut failed = 0;
+    let mut untranslated = 0;
+    for filepath in &args.filepaths {
+        let translation = match convert_file_with_options(filepath, &args.options) {
+            Ok(translation) => translation,
+            Err(error) => {
+                eprintln!("{}: {}", filepath.display(), error);
+                failed += 1;
+                continue;
+            }
+        };
+        let n = translation.untranslated();
+        eprintln!("{}: {} item(s) not translated", filepath.display(), n);
+        eprint!("{}", translation.unhandled_summary());
+        untranslated += n;
     }
-    Ok(())
-}
-
-fn macro_to_zig(m: &ItemMacro) -> Result<(), Error> {
-    if let Some(id) = path_as_single_ident(&m.mac.path) {
-        match id.as_str() {
-            "STRUCT" => struct_macro_to_zig(&m.mac.tokens),
-            "DECLARE_HANDLE" => declare_handle_to_zig(&m.mac.tokens),
-            "FN" => fn_macro_to_zig(&m.mac.tokens),
-            _ => Err(Error::Unhandled(id)),
-        }
-    } else {
-        Err(Error::Nyi)
+    if failed > 0 {
+        return Err(Error::FilesFailed(failed));
     }
+    check_strict(args, untranslated)
 }
 
-fn fn_to_zig(f: &ItemFn) -> Result<(), Error> {
-    Err(Error::Unhandled(f.sig.ident.to_string()))
-}
-
-fn item_to_zig(item: &Item, cx: &mut Cx) -> Result<(), Error> {
-    match item {
-        Item::Use(u) => use_to_zig(u, cx)?,
-        Item::Type(t) => type_to_zig(t)?,
-        Item::Const(c) => const_to_zig(c),
-        Item::ForeignMod(fm) => foreign_mod_to_zig(fm, cx)?,
-        Item::Macro(m) => macro_to_zig(m)?,
-        Item::Fn(f) => fn_to_zig(f)?,
-        _ => println!("{:#?}", item),
+fn try_main() -> Result<(), Error> {
+    let args = parse_args()?;
+    let output = &args.output;
+    if args.list_unhandled {
+        return list_unhandled(&args);
     }
-    Ok(())
-}
 
-fn wrap_item_to_zig(item: &Item, cx: &mut Cx) -> Result<(), Error> {
-    let result = item_to_zig(item, cx);
-    match result {
-        Err(Error::Unhandled(item_name)) => {
-            println!("// Unhandled item: {}", item_name);
-            return Ok(());
-        }
-        Err(Error::Nyi) => {
-            println!("// Item not yet implemented");
-            return Ok(());
+    // A single input goes to stdout or the `-o` file, unless `-o` names an
+    // existing directory or there's a `build.zig` to go with it.
+    let to_dir = output.as_ref().map(|o| o.is_dir()).unwrap_or(false);
+    match args.filepaths.as_slice() {
+        [] => return Err(Error::IncorrectUsage),
+        [filepath] if !to_dir && !args.emit_build_zig => {
+            // Translate into memory first so a failure doesn't truncate an
+            // earlier output.
+            let mut buf = Vec::new();
+            let translation = translate_file(filepath, &args, &mut buf)?;
+            match output {
+                Some(path) => fs::write(path, &buf).map_err(Error::WriteFile)?,
+                None => io::stdout().lock().write_all(&buf).map_err(Error::WriteFile)?,
+            }
+            return check_strict(&args, translation.untranslated());
         }
         _ => (),
     }
-    result
-}
-
-fn try_main() -> Result<(), Error> {
-    let mut args = env::args_os();
-    let _ = args.next(); // executable name
-
-    let filepath = match (args.next(), args.next()) {
-        (Some(arg), None) => PathBuf::from(arg),
-        _ => return Err(Error::IncorrectUsage),
-    };
 
-    let code = fs::read_to_string(&filepath).map_err(Error::ReadFile)?;
-    let syntax = syn::parse_file(&code).map_err({
-        |error| Error::ParseFile {
-            error,
-            filepath,
-            source_code: code,
+    // Otherwise each input gets its own `<stem>.zig`, next to the input or
+    // under the `-o` directory, where the inputs keep their paths from the
+    // directory containing them all.
+    let root = common_root(&args.filepaths);
+    let out_dir = output.as_deref().unwrap_or(&root);
+    let mut written = HashSet::new();
+    let mut names = HashSet::new();
+    let mut failed = 0;
+    let mut untranslated = 0;
+    let mut modules = Vec::new();
+    for filepath in &args.filepaths {
+        // The path from the output directory, where the `build.zig` goes.
+        let module_path = match filepath.strip_prefix(&root) {
+            Ok(path) if path.is_relative() => path.with_extension("zig"),
+            _ => PathBuf::from(filepath.with_extension("zig").file_name().unwrap()),
+        };
+        let zig_path = out_dir.join(&module_path);
+        let name = module_name(filepath)
+            .unwrap_or_else(|| filepath.file_stem().unwrap().to_string_lossy().into_owned());
+        // Translate into memory first so a failure doesn't leave a partial file.
+        let mut buf = Vec::new();
+        let result = if args.emit_build_zig && !names.insert(name.clone()) {
+            Err(Error::DuplicateModule(name.clone()))
+        } else if written.insert(zig_path.clone()) {
+            translate_file(filepath, &args, &mut buf).and_then(|translation| {
+                if let Some(dir) = zig_path.parent() {
+                    fs::create_dir_all(dir).map_err(Error::WriteFile)?;
+                }
+                fs::write(&zig_path, &buf).map_err(Error::WriteFile)?;
+                Ok(translation)
+            })
+        } else {
+            Err(Error::DuplicateOutput(zig_path))
+        };
+        match result {
+            Ok(translation) => {
+                untranslated += translation.untranslated();
+                modules.push(BuildModule {
+                    name,
+                    path: module_path.to_string_lossy().replace('\\', "/"),
+                    imports: translation.imports,
+                });
+            }
+            Err(error) => {
+                eprintln!("{}: {}", filepath.display(), error);
+                failed += 1;
+            }
         }
-    })?;
-    let mut cx = Cx {
-        link_name: "user32".into(),
-        toplevel_imports: Default::default(),
-    };
-    for item in &syntax.items {
-        wrap_item_to_zig(item, &mut cx)?;
+    }
+    if args.emit_build_zig {
+        fs::create_dir_all(out_dir).map_err(Error::WriteFile)?;
+        fs::write(out_dir.join("build.zig"), build_zig(&modules)).map_err(Error::WriteFile)?;
+    }
+    if failed > 0 {
+        return Err(Error::FilesFailed(failed));
     }
 
-    Ok(())
+    check_strict(&args, untranslated)
 }
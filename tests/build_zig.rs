@@ -0,0 +1,85 @@
+//! The `build.zig` declaring translated files as modules.
+
+use winapi_zig::{build_zig, convert_str, convert_str_with_options, BuildModule, Options};
+
+fn module(name: &str, source: &str) -> BuildModule {
+    BuildModule {
+        name: name.into(),
+        path: format!("{}.zig", name),
+        imports: convert_str(source).unwrap().imports,
+    }
+}
+
+#[test]
+fn modules() {
+    let modules = [
+        module("minwindef", "use ctypes::c_int; pub type BOOL = c_int;"),
+        module("windef", "use minwindef::BOOL;"),
+        module("winuser", "use minwindef::BOOL; use windef::HWND; use um::winnt::LONG;"),
+        module("test", "pub const X: u32 = 1;"),
+    ];
+    assert_eq!(modules[2].imports, ["minwindef.zig", "um.zig", "windef.zig"]);
+    let build = build_zig(&modules);
+    for name in ["minwindef", "windef", "winuser"] {
+        let source = format!(".{{ .source_file = .{{ .path = \"{}.zig\" }} }}", name);
+        let declaration = format!("    const {0} = b.addModule(\"{0}\", {1});\n", name, source);
+        assert!(build.contains(&declaration), "{}", build);
+    }
+    assert!(build.contains(
+        "    _ = b.addModule(\"test\", .{ .source_file = .{ .path = \"test.zig\" } });\n"
+    ));
+    assert!(build.contains(
+        "    winuser.dependencies.put(\"windef.zig\", windef) catch @panic(\"OOM\");\n"
+    ));
+    assert!(build.contains(
+        "    winuser.dependencies.put(\"minwindef.zig\", minwindef) catch @panic(\"OOM\");\n"
+    ));
+    assert!(build.contains(
+        "    windef.dependencies.put(\"minwindef.zig\", minwindef) catch @panic(\"OOM\");\n"
+    ));
+    assert!(!build.contains("\"um\""));
+}
+
+#[test]
+fn module_dirs() {
+    let mut options = Options::default();
+    options.module_dirs.insert("um".into());
+    options.module_dirs.insert("shared".into());
+    let module = |name: &str, path: &str, source: &str| BuildModule {
+        name: name.into(),
+        path: path.into(),
+        imports: convert_str_with_options(source, &options).unwrap().imports,
+    };
+    let modules = [
+        module("um", "um/mod.zig", "pub mod winuser;"),
+        module("shared", "shared/mod.zig", "pub mod minwindef;"),
+        module("winuser", "um/winuser.zig", "use shared::minwindef::DWORD;"),
+        module("minwindef", "shared/minwindef.zig", "pub type DWORD = u32;"),
+        module("winnt", "um/winnt.zig", "use um::winuser::HWND; use km::wdm::IRP;"),
+    ];
+    let build = build_zig(&modules);
+    assert!(build.contains(
+        "    winuser.dependencies.put(\"shared/minwindef.zig\", minwindef) catch @panic(\"OOM\");\n"
+    ));
+    assert!(build.contains(
+        "    winnt.dependencies.put(\"um/winuser.zig\", winuser) catch @panic(\"OOM\");\n"
+    ));
+    assert!(build.contains("    _ = b.addModule(\"um\", "));
+    assert!(!build.contains("wdm"));
+}
+
+#[test]
+fn mod_rs() {
+    let modules = [
+        BuildModule {
+            name: "um".into(),
+            path: "um/mod.zig".into(),
+            imports: Vec::new(),
+        },
+        module("winuser", "use um::winnt::LONG;"),
+    ];
+    let build = build_zig(&modules);
+    assert!(build.contains(
+        "    winuser.dependencies.put(\"um.zig\", um) catch @panic(\"OOM\");\n"
+    ));
+}
@@ -0,0 +1,33 @@
+//! Translating string pointer typedefs with `c_strings`.
+
+use winapi_zig::{convert_str, convert_str_with_options, Options};
+
+const SOURCE: &str = "
+pub type LPCSTR = *const c_char;
+pub type LPSTR = *mut CHAR;
+pub type LPCWSTR = *const wchar_t;
+pub type LPWSTR = *mut WCHAR;
+pub type LPCBYTE = *const BYTE;
+";
+
+#[test]
+fn narrow_and_wide() {
+    let options = Options {
+        c_strings: true,
+        ..Options::default()
+    };
+    let zig = convert_str_with_options(SOURCE, &options).unwrap().zig;
+    assert!(zig.contains("pub const LPCSTR = [*:0]const u8;"));
+    assert!(zig.contains("pub const LPSTR = [*:0]u8;"));
+    assert!(zig.contains("pub const LPCWSTR = [*:0]const u16;"));
+    assert!(zig.contains("pub const LPWSTR = [*:0]u16;"));
+    // Other pointers aren't strings.
+    assert!(zig.contains("pub const LPCBYTE = ?*const BYTE;"));
+}
+
+#[test]
+fn off_by_default() {
+    let zig = convert_str(SOURCE).unwrap().zig;
+    assert!(zig.contains("pub const LPCSTR = ?*const i8;"));
+    assert!(zig.contains("pub const LPCWSTR = ?*const u16;"));
+}
@@ -0,0 +1,4 @@
+pub const DEFAULT_FLAGS: DWORD = 0x0001;
+pub const DEFAULT_FLAGS_PTR: &DWORD = &DEFAULT_FLAGS;
+pub const FLAG_TABLE: [&DWORD; 2] = [&DEFAULT_FLAGS, &(DEFAULT_FLAGS)];
+pub const REREAD_FLAGS: DWORD = *DEFAULT_FLAGS_PTR;
@@ -0,0 +1,4 @@
+pub const DEFAULT_FLAGS = 0x0001;
+pub const DEFAULT_FLAGS_PTR = &DEFAULT_FLAGS;
+pub const FLAG_TABLE: [2]*const DWORD = [_]*const DWORD{ &DEFAULT_FLAGS, &(DEFAULT_FLAGS) };
+pub const REREAD_FLAGS = DEFAULT_FLAGS_PTR.*;
@@ -0,0 +1,11 @@
+extern "system" {
+    pub fn SetPoint(
+        (x, y): (c_int, c_int),
+        _: DWORD,
+        &flags: &DWORD,
+        mut count: c_int,
+    ) -> BOOL;
+}
+impl POINT {
+    pub fn set(&mut self, (x, y): (c_int, c_int)) {}
+}
@@ -0,0 +1,15 @@
+pub extern "user32" fn SetPoint (
+    arg0: struct { c_int, c_int },
+    _: DWORD,
+    arg2: *const DWORD,
+    count: c_int,
+) callconv(.C) c_int;
+
+pub fn POINT_set(
+    self: *POINT,
+    arg1: struct { c_int, c_int },
+) void {
+    _ = self;
+    _ = arg1;
+    @panic("unimplemented");
+}
@@ -0,0 +1,14 @@
+pub type PATH = [WCHAR; MAX_PATH];
+pub type LONG_PATH = [WCHAR; 2 * MAX_PATH];
+pub type PADDED = [u8; MAX_PATH + 1];
+pub type GRID = [u8; ROWS * COLUMNS];
+pub type NESTED = [[u8; COLUMNS]; ROWS - 1];
+pub type QUALIFIED = [u8; minwindef::MAX_PATH / 2];
+pub type MIXED = [u8; 2 * MAX_PATH + 1];
+pub type SHIFTED = [u8; 1 << 4];
+pub type TYPED = [u8; 16usize];
+STRUCT!{struct NAMES {
+    szName: [CHAR; MAX_MODULE_NAME32 + 1],
+    szExePath: [CHAR; MAX_PATH],
+}}
+pub type CALLED = [u8; size_of::<u32>()];
@@ -0,0 +1,16 @@
+pub const PATH = [MAX_PATH]WCHAR;
+pub const LONG_PATH = [2 * MAX_PATH]WCHAR;
+pub const PADDED = [MAX_PATH + 1]u8;
+pub const GRID = [ROWS * COLUMNS]u8;
+pub const NESTED = [ROWS - 1][COLUMNS]u8;
+pub const QUALIFIED = [minwindef.MAX_PATH / 2]u8;
+pub const MIXED = [(2 * MAX_PATH) + 1]u8;
+pub const SHIFTED = [1 << 4]u8;
+pub const TYPED = [16]u8;
+
+pub const NAMES = extern struct {
+    szName: [MAX_MODULE_NAME32 + 1]CHAR,
+    szExePath: [MAX_PATH]CHAR,
+};
+
+pub const CALLED = [@sizeOf(u32)]u8;
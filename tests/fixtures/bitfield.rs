@@ -0,0 +1,28 @@
+STRUCT!{struct PROCESSOR_FLAGS {
+    Flags: DWORD,
+}}
+BITFIELD!{PROCESSOR_FLAGS Flags: DWORD [
+    Enabled set_Enabled[0..1],
+    Mode set_Mode[1..4],
+    Count set_Count[4..32],
+]}
+BITFIELD!{DCB BitFields: DWORD [
+    fBinary set_fBinary[0..1],
+    fParity set_fParity[1..2],
+    fDtrControl set_fDtrControl[4..6],
+    fAbortOnError set_fAbortOnError[14..15],
+]}
+BITFIELD!{SMALL Bits: BYTE [
+    Low set_Low[0..4],
+    High set_High[4..8],
+]}
+BITFIELD!{OVERLAP Bits: DWORD [
+    A set_A[0..4],
+    B set_B[2..6],
+]}
+BITFIELD!{TOO_WIDE Bits: WORD [
+    A set_A[0..17],
+]}
+BITFIELD!{SIGNED Bits: LONG_WRAPPER [
+    A set_A[0..1],
+]}
@@ -0,0 +1,24 @@
+pub const PROCESSOR_FLAGS = extern struct {
+    Flags: DWORD,
+};
+
+pub const PROCESSOR_FLAGS_Flags = packed struct(u32) {
+    Enabled: u1,
+    Mode: u3,
+    Count: u28,
+};
+
+pub const DCB_BitFields = packed struct(u32) {
+    fBinary: u1,
+    fParity: u1,
+    _reserved2: u2,
+    fDtrControl: u2,
+    _reserved6: u8,
+    fAbortOnError: u1,
+    _reserved15: u17,
+};
+
+pub const SMALL_Bits = packed struct(u8) {
+    Low: u4,
+    High: u4,
+};
@@ -0,0 +1,7 @@
+pub type OWNED = Box<RECT>;
+pub type ITEMS = Vec<u16>;
+pub type OPAQUE = Box<c_void>;
+STRUCT!{struct LIST {
+    head: Box<NODE>,
+    names: Vec<*const u16>,
+}}
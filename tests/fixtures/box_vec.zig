@@ -0,0 +1,8 @@
+pub const OWNED = *RECT;
+pub const ITEMS = []u16;
+pub const OPAQUE = *anyopaque;
+
+pub const LIST = extern struct {
+    head: *NODE,
+    names: []?*const u16,
+};
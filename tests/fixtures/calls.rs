@@ -0,0 +1,8 @@
+STRUCT!{struct POINT_PAIR(LONG, PhantomData<u8>, LONG);}
+STRUCT!{#[repr(transparent)] struct HRESULT_CODE(PhantomData<u8>, u32);}
+pub const ORIGIN: POINT_PAIR = POINT_PAIR(0, PhantomData, 0x10);
+pub const S_OK: HRESULT_CODE = HRESULT_CODE(PhantomData, 0);
+pub const LONG_MAX_PAIR: DWORD = MAKELONG(0xffff, 0x7fff);
+pub const POINT_SIZE: usize = size_of::<POINT_PAIR>() * 2;
+pub const POINT_ALIGN: usize = ::core::mem::align_of::<POINT_PAIR>();
+pub const NESTED: u32 = outer::inner(1);
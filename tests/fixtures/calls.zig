@@ -0,0 +1,12 @@
+pub const POINT_PAIR = extern struct {
+    @"0": LONG,
+    @"2": LONG,
+};
+
+pub const HRESULT_CODE = u32;
+pub const ORIGIN = POINT_PAIR{ .@"0" = 0, .@"2" = 0x10 };
+pub const S_OK = @as(HRESULT_CODE, 0);
+pub const LONG_MAX_PAIR = MAKELONG(0xffff, 0x7fff);
+pub const POINT_SIZE = @sizeOf(POINT_PAIR) * 2;
+pub const POINT_ALIGN = @alignOf(POINT_PAIR);
+pub const NESTED = outer.inner(1);
@@ -0,0 +1,17 @@
+pub const POINTER_SIZE: usize = if cfg!(target_pointer_width = "64") { 8 } else { 4 };
+pub const ALIGNMENT: usize = if cfg!(target_arch = "x86") {
+    4
+} else if cfg!(target_arch = "aarch64") {
+    16
+} else {
+    8
+};
+pub const WIDE: bool = cfg!(windows);
+pub const MAX_COUNT: DWORD = if USE_LARGE_COUNT { 0xffff } else { 0xff };
+pub const SMALL: bool = MAX_COUNT < 0x100 && WIDE;
+pub const UNKNOWN: usize = if cfg!(feature = "big") { 2 } else { 1 };
+pub const NARROW: bool = if !cfg!(target_pointer_width = "64") { true } else { false };
+pub const DECIDED: usize = if cfg!(feature = "big") && cfg!(unix) { 1 } else { 2 };
+pub const UNDECIDED: usize = if cfg!(feature = "big") && cfg!(windows) { 1 } else { 2 };
+pub const NOT_WIDE: bool = !WIDE || !(MAX_COUNT > 0xff);
+pub const LARGE: bool = !SMALL;
@@ -0,0 +1,9 @@
+pub const POINTER_SIZE = 8;
+pub const ALIGNMENT = 8;
+pub const WIDE = true;
+pub const MAX_COUNT = if (USE_LARGE_COUNT) 0xffff else 0xff;
+pub const SMALL = (MAX_COUNT < 0x100) and WIDE;
+pub const NARROW = false;
+pub const DECIDED = 2;
+pub const NOT_WIDE = !WIDE or !(MAX_COUNT > 0xff);
+pub const LARGE = !SMALL;
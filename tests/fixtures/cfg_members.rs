@@ -0,0 +1,30 @@
+STRUCT!{struct PADDED {
+    #[cfg(target_pointer_width = "32")]
+    pad: u32,
+    #[cfg(target_pointer_width = "64")]
+    pad: u64,
+    #[cfg(feature = "extra")]
+    extra: u8,
+    u: union {
+        #[cfg(target_arch = "x86")]
+        narrow: u32,
+        wide: u64,
+    },
+}}
+STRUCT!{#[repr(align(16))] struct ALIGNED {
+    #[cfg(target_arch = "x86")]
+    legacy: u32,
+    value: u64,
+}}
+pub enum ARCH {
+    #[cfg(target_arch = "x86")]
+    X86 = 1,
+    #[cfg(target_arch = "x86_64")]
+    X64 = 2,
+}
+impl PADDED {
+    #[cfg(target_arch = "x86")]
+    pub const ALIGN: usize = 4;
+    #[cfg(target_arch = "x86_64")]
+    pub const ALIGN: usize = 8;
+}
@@ -0,0 +1,18 @@
+pub const PADDED = extern struct {
+    pad: u64,
+    // #[cfg(feature = "extra")]
+    extra: u8,
+    u: extern union {
+        wide: u64,
+    },
+
+    pub const ALIGN = 8;
+};
+
+pub const ALIGNED = extern struct {
+    value: u64 align(16),
+};
+
+pub const ARCH = enum(c_int) {
+    X64 = 2,
+};
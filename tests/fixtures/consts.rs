@@ -0,0 +1,33 @@
+pub type HANDLE = *mut c_void;
+pub type LPCWSTR = *const WCHAR;
+pub type LONG = c_long;
+pub const WS_OVERLAPPED: DWORD = 0x00000000;
+pub const WS_POPUP: DWORD = 0x80000000;
+pub const WS_CAPTION: DWORD = 0x00C00000;
+pub const WS_SYSMENU: DWORD = 0x00080000;
+pub const WS_OVERLAPPEDWINDOW: DWORD = WS_OVERLAPPED | WS_CAPTION | WS_SYSMENU;
+pub const CW_USEDEFAULT: c_int = -2147483648;
+pub const MAX_PATH: usize = 260;
+pub const SUFFIXED: u32 = 0x8000_u32;
+pub const BINARY: u8 = 0b1010u8;
+pub const SHIFTED: DWORD = 1 << 4 | 1 << 5;
+pub const INVALID_HANDLE_VALUE: HANDLE = -1isize as HANDLE;
+pub const NULL_PTR: *mut c_void = 0 as *mut c_void;
+pub const CLASS_NAME: &str = "Window \"Class\"\n";
+pub const RT_BYTES: &[u8] = b"ab\x00\xff";
+pub const GROUPED: DWORD = (WS_POPUP | WS_CAPTION) & WS_SYSMENU;
+pub const GROUPED_RIGHT: DWORD = WS_POPUP & (WS_CAPTION | WS_SYSMENU);
+pub const NOT_GROUPED: DWORD = !(WS_POPUP | WS_CAPTION);
+pub const DOUBLE: DWORD = ((1 << 2));
+pub const NO_NAME: LPCWSTR = 0 as LPCWSTR;
+pub const NO_DATA: *const u8 = 0 as *const u8;
+pub const HIGH_BIT: *mut c_void = 0x8000 as *mut c_void;
+pub const LANGS: [WORD; 2] = [0x0409, 0x0809];
+pub const MAX_LONG: LONG = 0x7fff_ffff as LONG;
+pub const INVALID_PTR: *mut c_void = -1isize as *mut c_void;
+pub const HKEY_CLASSES_ROOT: HKEY = 0x80000000 as HKEY;
+pub const MINUS_ONE: c_int = -1;
+pub const NEGATIVE_HEX: c_long = -0x7fff_ffff;
+pub const ALL_ONES: u32 = !0u32;
+pub const ALL_DWORD: DWORD = !0;
+pub const NARROW_NOT: u32 = !0u8 as u32;
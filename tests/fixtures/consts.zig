@@ -0,0 +1,33 @@
+pub const HANDLE = ?*anyopaque;
+pub const LPCWSTR = ?*const WCHAR;
+pub const LONG = c_long;
+pub const WS_OVERLAPPED = 0x00000000;
+pub const WS_POPUP = 0x80000000;
+pub const WS_CAPTION = 0x00C00000;
+pub const WS_SYSMENU = 0x00080000;
+pub const WS_OVERLAPPEDWINDOW = WS_OVERLAPPED | WS_CAPTION | WS_SYSMENU;
+pub const CW_USEDEFAULT = -2147483648;
+pub const MAX_PATH = 260;
+pub const SUFFIXED = 0x8000;
+pub const BINARY = 0b1010;
+pub const SHIFTED = (1 << 4) | (1 << 5);
+pub const INVALID_HANDLE_VALUE = @as(HANDLE, @ptrFromInt(@as(usize, @bitCast(@as(isize, -1)))));
+pub const NULL_PTR: ?*anyopaque = null;
+pub const CLASS_NAME = "Window \"Class\"\n";
+pub const RT_BYTES = "ab\x00\xff";
+pub const GROUPED = (WS_POPUP | WS_CAPTION) & WS_SYSMENU;
+pub const GROUPED_RIGHT = WS_POPUP & (WS_CAPTION | WS_SYSMENU);
+pub const NOT_GROUPED = ~@as(DWORD, WS_POPUP | WS_CAPTION);
+pub const DOUBLE = ((1 << 2));
+pub const NO_NAME = @as(LPCWSTR, null);
+pub const NO_DATA: ?*const u8 = null;
+pub const HIGH_BIT: ?*anyopaque = @as(?*anyopaque, @ptrFromInt(0x8000));
+pub const LANGS: [2]WORD = [_]WORD{ 0x0409, 0x0809 };
+pub const MAX_LONG = @as(LONG, 0x7fff_ffff);
+pub const INVALID_PTR: ?*anyopaque = @as(?*anyopaque, @ptrFromInt(@as(usize, @bitCast(@as(isize, -1)))));
+pub const HKEY_CLASSES_ROOT = @as(HKEY, @ptrFromInt(0x80000000));
+pub const MINUS_ONE = -1;
+pub const NEGATIVE_HEX = -0x7fff_ffff;
+pub const ALL_ONES = ~@as(u32, 0);
+pub const ALL_DWORD = ~@as(DWORD, 0);
+pub const NARROW_NOT = @as(u32, ~@as(u8, 0));
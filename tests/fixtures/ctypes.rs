@@ -0,0 +1,17 @@
+pub type SCHAR = c_schar;
+pub type UCHAR = c_uchar;
+pub type USHORT = c_ushort;
+pub type INT = c_int;
+pub type UINT = c_uint;
+pub type ULONG = c_ulong;
+pub type LONGLONG = c_longlong;
+pub type DWORDLONG = c_ulonglong;
+pub type FLOAT = c_float;
+pub type DOUBLE = c_double;
+pub type INT8 = __int8;
+pub type UINT8 = __uint8;
+pub type INT16 = __int16;
+pub type UINT16 = __uint16;
+pub type INT32 = __int32;
+pub type UINT32 = __uint32;
+pub type INT64 = __int64;
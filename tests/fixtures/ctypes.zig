@@ -0,0 +1,17 @@
+pub const SCHAR = i8;
+pub const UCHAR = u8;
+pub const USHORT = c_ushort;
+pub const INT = c_int;
+pub const UINT = c_uint;
+pub const ULONG = c_ulong;
+pub const LONGLONG = c_longlong;
+pub const DWORDLONG = c_ulonglong;
+pub const FLOAT = f32;
+pub const DOUBLE = f64;
+pub const INT8 = i8;
+pub const UINT8 = u8;
+pub const INT16 = i16;
+pub const UINT16 = u16;
+pub const INT32 = i32;
+pub const UINT32 = u32;
+pub const INT64 = i64;
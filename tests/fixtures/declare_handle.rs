@@ -0,0 +1,10 @@
+DECLARE_HANDLE!{HWND, HWND__}
+DECLARE_HANDLE!{HINSTANCE, HINSTANCE__}
+DECLARE_HANDLE!{HGDIOBJ, HGDIOBJ__}
+DECLARE_HANDLE!{HMONITOR, HMONITOR__,}
+DECLARE_HANDLE!{HKEY}
+DECLARE_HANDLE!{HDESK,}
+DECLARE_HANDLE!{HMENU HMENU__}
+DECLARE_HANDLE!{HICON, HICON__, extra}
+DECLARE_HANDLE!{}
+pub const AFTER: u32 = 1;
@@ -0,0 +1,19 @@
+pub const HWND__ = opaque {};
+pub const HWND = ?*HWND__;
+
+pub const HINSTANCE__ = opaque {};
+pub const HINSTANCE = ?*HINSTANCE__;
+
+pub const HGDIOBJ__ = opaque {};
+pub const HGDIOBJ = ?*HGDIOBJ__;
+
+pub const HMONITOR__ = opaque {};
+pub const HMONITOR = ?*HMONITOR__;
+
+pub const HKEY__ = opaque {};
+pub const HKEY = ?*HKEY__;
+
+pub const HDESK__ = opaque {};
+pub const HDESK = ?*HDESK__;
+
+pub const AFTER = 1;
@@ -0,0 +1,16 @@
+extern "system" {
+    /// Sets the window's text.
+    #[deprecated(since = "0.3.0", note = "Use SetWindowTextW")]
+    pub fn SetWindowText(
+        hWnd: HWND,
+        lpString: LPCWSTR,
+    ) -> BOOL;
+    #[deprecated = "Use GetWindowTextW"]
+    pub fn GetWindowText(
+        hWnd: HWND,
+    ) -> c_int;
+}
+#[deprecated]
+pub const OLD_FLAG: DWORD = 1;
+#[deprecated(since = "0.2.0")]
+pub type OLD_TYPE = DWORD;
@@ -0,0 +1,17 @@
+/// Sets the window's text.
+/// Deprecated since 0.3.0: Use SetWindowTextW
+pub extern "user32" fn SetWindowText (
+    hWnd: HWND,
+    lpString: LPCWSTR,
+) callconv(.C) c_int;
+
+/// Deprecated: Use GetWindowTextW
+pub extern "user32" fn GetWindowText (
+    hWnd: HWND,
+) callconv(.C) c_int;
+
+/// Deprecated.
+pub const OLD_FLAG = 1;
+
+/// Deprecated since 0.2.0.
+pub const OLD_TYPE = DWORD;
@@ -0,0 +1,27 @@
+#[repr(u8)]
+pub enum Small {
+    A,
+    B = 0xff,
+}
+#[repr(C, i64)]
+pub enum Wide {
+    Big = -1,
+}
+pub enum Default {
+    X,
+}
+ENUM!{enum MACRO {
+    M = 1,
+}}
+#[repr(transparent)]
+pub enum Single {
+    Only,
+}
+#[repr(align(8))]
+pub enum Aligned {
+    One,
+}
+#[repr(f32)]
+pub enum Float {
+    F,
+}
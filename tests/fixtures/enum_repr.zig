@@ -0,0 +1,16 @@
+pub const Small = enum(u8) {
+    A,
+    B = 0xff,
+};
+
+pub const Wide = enum(i64) {
+    Big = -1,
+};
+
+pub const Default = enum(c_int) {
+    X,
+};
+
+pub const MACRO = enum(c_int) {
+    M = 1,
+};
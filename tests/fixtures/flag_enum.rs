@@ -0,0 +1,18 @@
+ENUM!{enum FILE_FLAGS {
+    FLAG_READ = 1,
+    FLAG_WRITE = 2,
+    FLAG_EXECUTE = 4,
+}}
+DEFINE_ENUM_FLAG_OPERATORS!(FILE_FLAGS);
+ENUM!{enum PLAIN {
+    PLAIN_A,
+    PLAIN_B,
+}}
+pub mod inner {
+    #[repr(u8)]
+    pub enum MODE {
+        MODE_A = 1,
+        MODE_B = 2,
+    }
+    DEFINE_ENUM_FLAG_OPERATORS!{MODE}
+}
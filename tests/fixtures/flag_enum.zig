@@ -0,0 +1,19 @@
+pub const FILE_FLAGS = enum(c_int) {
+    FLAG_READ = 1,
+    FLAG_WRITE = 2,
+    FLAG_EXECUTE = 4,
+    _,
+};
+
+pub const PLAIN = enum(c_int) {
+    PLAIN_A,
+    PLAIN_B,
+};
+
+pub const inner = struct {
+    pub const MODE = enum(u8) {
+        MODE_A = 1,
+        MODE_B = 2,
+        _,
+    };
+};
@@ -0,0 +1,27 @@
+/// Combine two words into a long.
+#[inline]
+pub const fn MAKELONG(a: u16, b: u16) -> u32 {
+    (a as u32) | ((b as u32) << 16)
+}
+pub fn LOWORD(l: u32) -> u16 {
+    return (l & 0xffff) as u16;
+}
+pub extern "system" fn ZERO(_: u32, unused: u32) -> u32 {
+    0
+}
+pub fn GET_X_LPARAM(lp: LPARAM) -> c_int {
+    let x = LOWORD(lp as u32);
+    x as c_int
+}
+pub fn LOBYTE(w: u16) -> i8 {
+    (w & 0xff) as u8 as i8
+}
+pub fn EXTEND(l: i16) -> i64 {
+    l as i64
+}
+pub fn SIGNED(l: u32) -> i16 {
+    l as i16
+}
+pub fn OPAQUE(h: HANDLE) -> u16 {
+    h as u16
+}
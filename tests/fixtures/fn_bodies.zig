@@ -0,0 +1,53 @@
+/// Combine two words into a long.
+pub fn MAKELONG(
+    a: u16,
+    b: u16,
+) u32 {
+    return (@as(u32, a)) | ((@as(u32, b)) << 16);
+}
+
+pub fn LOWORD(
+    l: u32,
+) u16 {
+    return @as(u16, @truncate(l & 0xffff));
+}
+
+pub fn ZERO(
+    _: u32,
+    unused: u32,
+) callconv(.C) u32 {
+    _ = unused;
+    return 0;
+}
+
+pub fn GET_X_LPARAM(
+    lp: LPARAM,
+) c_int {
+    _ = lp;
+    @panic("unimplemented");
+}
+
+pub fn LOBYTE(
+    w: u16,
+) i8 {
+    return @as(i8, @bitCast(@as(u8, @truncate(w & 0xff))));
+}
+
+pub fn EXTEND(
+    l: i16,
+) i64 {
+    return @as(i64, l);
+}
+
+pub fn SIGNED(
+    l: u32,
+) i16 {
+    return @as(i16, @bitCast(@as(u16, @truncate(l))));
+}
+
+pub fn OPAQUE(
+    h: HANDLE,
+) u16 {
+    _ = h;
+    @panic("unimplemented");
+}
@@ -0,0 +1,31 @@
+extern "system" {
+    pub fn MessageBoxW(
+        hWnd: HWND,
+        lpText: LPCWSTR,
+        lpCaption: LPCWSTR,
+        uType: UINT,
+    ) -> c_int;
+    pub fn PostQuitMessage(
+        nExitCode: c_int,
+    );
+    pub fn GetMessageW(
+        lpMsg: LPMSG,
+        hWnd: HWND,
+        wMsgFilterMin: UINT,
+        wMsgFilterMax: UINT,
+    ) -> BOOL;
+}
+extern "C" {
+    pub fn wsprintfW(
+        unnamedParam1: LPWSTR,
+        unnamedParam2: LPCWSTR,
+        ...
+    ) -> c_int;
+}
+#[link(name = "gdi32")]
+extern "system" {
+    #[link_name = "GetStockObject"]
+    pub fn get_stock_object(
+        i: c_int,
+    ) -> HGDIOBJ;
+}
@@ -0,0 +1,28 @@
+pub extern "user32" fn MessageBoxW (
+    hWnd: HWND,
+    lpText: LPCWSTR,
+    lpCaption: LPCWSTR,
+    uType: UINT,
+) callconv(.C) c_int;
+
+pub extern "user32" fn PostQuitMessage (
+    nExitCode: c_int,
+) callconv(.C) void;
+
+pub extern "user32" fn GetMessageW (
+    lpMsg: LPMSG,
+    hWnd: HWND,
+    wMsgFilterMin: UINT,
+    wMsgFilterMax: UINT,
+) callconv(.C) c_int;
+
+pub extern "user32" fn wsprintfW (
+    unnamedParam1: LPWSTR,
+    unnamedParam2: LPCWSTR,
+    ...
+) callconv(.C) c_int;
+
+pub extern "gdi32" fn GetStockObject (
+    i: c_int,
+) callconv(.C) HGDIOBJ;
+pub const get_stock_object = GetStockObject;
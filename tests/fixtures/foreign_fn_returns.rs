@@ -0,0 +1,28 @@
+extern "system" {
+    pub fn CreateWindowExW(
+        dwExStyle: DWORD,
+        lpClassName: LPCWSTR,
+        lpWindowName: LPCWSTR,
+        dwStyle: DWORD,
+        x: c_int,
+        y: c_int,
+        nWidth: c_int,
+        nHeight: c_int,
+        hWndParent: HWND,
+        hMenu: HMENU,
+        hInstance: HINSTANCE,
+        lpParam: LPVOID,
+    ) -> HWND;
+    pub fn GetCommandLineW() -> LPWSTR;
+    pub fn HeapAlloc(
+        hHeap: HANDLE,
+        dwFlags: DWORD,
+        dwBytes: SIZE_T,
+    ) -> *mut c_void;
+    pub fn GetEnvironmentStrings() -> *mut u8;
+    pub fn CommandLineToArgvW(
+        lpCmdLine: LPCWSTR,
+        pNumArgs: *mut c_int,
+    ) -> *mut *mut u16;
+    pub fn GetLastError() -> DWORD;
+}
@@ -0,0 +1,34 @@
+pub extern "user32" fn CreateWindowExW (
+    dwExStyle: DWORD,
+    lpClassName: LPCWSTR,
+    lpWindowName: LPCWSTR,
+    dwStyle: DWORD,
+    x: c_int,
+    y: c_int,
+    nWidth: c_int,
+    nHeight: c_int,
+    hWndParent: HWND,
+    hMenu: HMENU,
+    hInstance: HINSTANCE,
+    lpParam: LPVOID,
+) callconv(.C) HWND;
+
+pub extern "user32" fn GetCommandLineW (
+) callconv(.C) LPWSTR;
+
+pub extern "user32" fn HeapAlloc (
+    hHeap: HANDLE,
+    dwFlags: DWORD,
+    dwBytes: SIZE_T,
+) callconv(.C) ?*anyopaque;
+
+pub extern "user32" fn GetEnvironmentStrings (
+) callconv(.C) ?*u8;
+
+pub extern "user32" fn CommandLineToArgvW (
+    lpCmdLine: LPCWSTR,
+    pNumArgs: ?*c_int,
+) callconv(.C) ?*?*u16;
+
+pub extern "user32" fn GetLastError (
+) callconv(.C) DWORD;
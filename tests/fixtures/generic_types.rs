@@ -0,0 +1,6 @@
+/// A mutable pointer to `T`.
+pub type Wrapper<T> = *mut T;
+pub type Pair<A, B> = (A, B);
+pub type Buffer<const N: usize> = [u8; N];
+pub type Borrowed<'a, T: Copy> = &'a T;
+pub type OnlyLifetime<'a> = &'a u8;
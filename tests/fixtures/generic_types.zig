@@ -0,0 +1,18 @@
+/// A mutable pointer to `T`.
+pub fn Wrapper(comptime T: type) type {
+    return ?*T;
+}
+
+pub fn Pair(comptime A: type, comptime B: type) type {
+    return struct { A, B };
+}
+
+pub fn Buffer(comptime N: usize) type {
+    return [N]u8;
+}
+
+pub fn Borrowed(comptime T: type) type {
+    return *const T;
+}
+
+pub const OnlyLifetime = *const u8;
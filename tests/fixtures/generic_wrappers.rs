@@ -0,0 +1,10 @@
+UNION!{union VALUE {
+    [u32; 1],
+    Int Int_mut: UnionField<u32>,
+    Float Float_mut: UnionField<f32>,
+}}
+STRUCT!{struct FLAGS {
+    _bitfield_1: __BindgenBitfieldUnit<[u8; 4]>,
+}}
+pub type WRAPPED = UnionField<*mut c_void>;
+pub type CELL = Cell<u32>;
@@ -0,0 +1,10 @@
+pub const VALUE = extern union {
+    Int: u32,
+    Float: f32,
+};
+
+pub const FLAGS = extern struct {
+    _bitfield_1: [4]u8,
+};
+
+pub const WRAPPED = ?*anyopaque;
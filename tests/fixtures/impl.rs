@@ -0,0 +1,26 @@
+STRUCT!{struct RECT {
+    left: LONG,
+    top: LONG,
+    right: LONG,
+    bottom: LONG,
+}}
+impl RECT {
+    /// The width of the rectangle.
+    pub fn width(&self) -> LONG {
+        self.right - self.left
+    }
+    pub fn offset(&mut self, dx: LONG, _: LONG) {
+        self.left += dx;
+    }
+    pub fn new() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+    pub fn map<T>(self) -> T {
+        unimplemented!()
+    }
+}
+impl Default for RECT {
+    fn default() -> Self {
+        RECT::new()
+    }
+}
@@ -0,0 +1,29 @@
+pub const RECT = extern struct {
+    left: LONG,
+    top: LONG,
+    right: LONG,
+    bottom: LONG,
+};
+
+/// The width of the rectangle.
+pub fn RECT_width(
+    self: *const RECT,
+) LONG {
+    _ = self;
+    @panic("unimplemented");
+}
+
+pub fn RECT_offset(
+    self: *RECT,
+    dx: LONG,
+    _: LONG,
+) void {
+    _ = self;
+    _ = dx;
+    @panic("unimplemented");
+}
+
+pub fn RECT_new(
+) RECT {
+    @panic("unimplemented");
+}
@@ -0,0 +1,13 @@
+RIDL!{#[uuid(0x00000000, 0x0000, 0x0000, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46)]
+interface IUnknown(IUnknownVtbl) {
+    fn AddRef() -> ULONG,
+}}
+impl IUnknown {
+    /// The size of an interface pointer.
+    pub const POINTER_SIZE: usize = size_of::<*mut Self>();
+    pub const MAX_REFS: ULONG = 0xffff_ffff;
+}
+// Declared in another file, so not namespaced.
+impl GUID {
+    pub const NULL_DATA1: u32 = 0;
+}
@@ -0,0 +1,14 @@
+pub const IUnknownVtbl = extern struct {
+    AddRef: *const fn(This: *IUnknown) callconv(.C) ULONG,
+};
+pub const IUnknown = extern struct {
+    lpVtbl: *const IUnknownVtbl,
+    pub const uuid = GUID{ .Data1 = 0x00000000, .Data2 = 0x0000, .Data3 = 0x0000, .Data4 = .{ 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46 } };
+
+    /// The size of an interface pointer.
+    pub const POINTER_SIZE = @sizeOf(?*IUnknown);
+
+    pub const MAX_REFS = 0xffff_ffff;
+};
+
+pub const GUID_NULL_DATA1 = 0;
@@ -0,0 +1,5 @@
+use um::winuser::HWND;
+use shared::minwindef::DWORD;
+use um::winnt::HANDLE;
+use ctypes::c_int;
+pub type WINDOW = HWND;
@@ -0,0 +1,7 @@
+const shared = @import("shared.zig");
+const um = @import("um.zig");
+
+const HWND = um.winuser.HWND;
+const DWORD = shared.minwindef.DWORD;
+const HANDLE = um.winnt.HANDLE;
+pub const WINDOW = HWND;
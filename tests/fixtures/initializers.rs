@@ -0,0 +1,13 @@
+DECLARE_HANDLE!{HWND, HWND__}
+pub const ZERO_GUID_DATA: [u8; 8] = [0; 8];
+pub const MAGIC: [u8; 4] = [0x4d, 0x5a, 0x90, 0];
+pub const EMPTY: [u16; 0] = [];
+pub const GRID: [[u8; 2]; 2] = [[1, 2], [3, 4]];
+pub const ROWS: [[u8; 4]; 3] = [[0; 4]; 3];
+pub static mut BUFFER: [WCHAR; MAX_PATH] = [0; MAX_PATH];
+pub const ORIGIN: POINT = POINT { x: 0, y: 0 };
+pub const UNIT: RECT = RECT { left: 0, top: 0, right: 1, bottom: -1 };
+pub const NESTED: MSG = MSG { hwnd: 0 as HWND, pt: POINT { x: 1, y: 2 }, data: [1, 2] };
+pub const QUALIFIED: POINT = um::windef::POINT { x: 3, y: 4 };
+pub const NONE: EMPTY_S = EMPTY_S {};
+pub const BASED: POINT = POINT { x: 5, ..ORIGIN };
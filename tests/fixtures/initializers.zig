@@ -0,0 +1,14 @@
+pub const HWND__ = opaque {};
+pub const HWND = ?*HWND__;
+
+pub const ZERO_GUID_DATA: [8]u8 = [_]u8{0} ** 8;
+pub const MAGIC: [4]u8 = [_]u8{ 0x4d, 0x5a, 0x90, 0 };
+pub const EMPTY: [0]u16 = [_]u16{};
+pub const GRID: [2][2]u8 = [_][2]u8{ [_]u8{ 1, 2 }, [_]u8{ 3, 4 } };
+pub const ROWS: [3][4]u8 = [_][4]u8{[_]u8{0} ** 4} ** 3;
+pub var BUFFER: [MAX_PATH]WCHAR = [_]WCHAR{0} ** MAX_PATH;
+pub const ORIGIN = POINT{ .x = 0, .y = 0 };
+pub const UNIT = RECT{ .left = 0, .top = 0, .right = 1, .bottom = -1 };
+pub const NESTED = MSG{ .hwnd = @as(HWND, null), .pt = POINT{ .x = 1, .y = 2 }, .data = .{ 1, 2 } };
+pub const QUALIFIED = um.windef.POINT{ .x = 3, .y = 4 };
+pub const NONE = EMPTY_S{};
@@ -0,0 +1,10 @@
+STRUCT!{struct OVERLAPPED {
+    Internal: ULONG_PTR,
+    InternalHigh: ULONG_PTR,
+    u: union {
+        /// The file position to start at.
+        s: OVERLAPPED_u_s,
+        Pointer: PVOID,
+    },
+    hEvent: HANDLE,
+}}
@@ -0,0 +1,10 @@
+pub const OVERLAPPED = extern struct {
+    Internal: ULONG_PTR,
+    InternalHigh: ULONG_PTR,
+    u: extern union {
+        /// The file position to start at.
+        s: OVERLAPPED_u_s,
+        Pointer: PVOID,
+    },
+    hEvent: HANDLE,
+};
@@ -0,0 +1,3 @@
+#![allow(non_snake_case)]
+#![cfg_attr(feature = "docs", doc = "unused")]
+pub const A: u32 = 1;
@@ -0,0 +1 @@
+pub const A = 1;
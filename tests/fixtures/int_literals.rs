@@ -0,0 +1,8 @@
+pub const DECIMAL: u32 = 1_000_000;
+pub const HEX: u32 = 0xDEAD_beef;
+pub const HEX_SUFFIXED: u64 = 0xffff_ffff_ffff_ffffu64;
+pub const OCTAL: u32 = 0o755;
+pub const OCTAL_SUFFIXED: u16 = 0o17_u16;
+pub const BINARY: u8 = 0b1010_0101;
+pub const SUFFIXED: usize = 42usize;
+pub const UNDERSCORE_SUFFIXED: i64 = 7_i64;
@@ -0,0 +1,8 @@
+pub const DECIMAL = 1_000_000;
+pub const HEX = 0xDEAD_beef;
+pub const HEX_SUFFIXED = 0xffff_ffff_ffff_ffff;
+pub const OCTAL = 0o755;
+pub const OCTAL_SUFFIXED = 0o17;
+pub const BINARY = 0b1010_0101;
+pub const SUFFIXED = 42;
+pub const UNDERSCORE_SUFFIXED = 7;
@@ -0,0 +1,15 @@
+use shared::minwindef::{DWORD, WORD as MyWord};
+use um::winnt::*;
+/// The number of entries.
+pub static COUNT: u32 = 5;
+static mut STATE: c_int = -1;
+pub enum Mixed {
+    A,
+    B = 5,
+    C,
+}
+pub mod inner {
+    pub const A: u32 = 1;
+    pub type B = u32;
+}
+pub mod winuser;
@@ -0,0 +1,25 @@
+const shared = @import("shared.zig");
+const um = @import("um.zig");
+
+const DWORD = shared.minwindef.DWORD;
+const MyWord = shared.minwindef.WORD;
+
+// Glob import of um.winnt can't be expanded.
+
+/// The number of entries.
+pub const COUNT: u32 = 5;
+
+var STATE: c_int = -1;
+
+pub const Mixed = enum(c_int) {
+    A,
+    B = 5,
+    C,
+};
+
+pub const inner = struct {
+    pub const A = 1;
+    pub const B = u32;
+};
+
+pub const winuser = @import("winuser.zig");
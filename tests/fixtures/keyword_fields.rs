@@ -0,0 +1,24 @@
+STRUCT!{struct ITEM {
+    r#type: DWORD,
+    align: WORD,
+    error: LONG,
+    u8: BYTE,
+    name: LPCWSTR,
+}}
+UNION!{union VALUE {
+    [u32; 1],
+    r#fn fn_mut: u32,
+}}
+ENUM!{enum KIND {
+    null = 0,
+    test,
+}}
+extern "system" {
+    pub fn SetType(
+        r#type: DWORD,
+        callback: Option<unsafe extern "system" fn(r#struct: LPVOID)>,
+    );
+}
+pub const fn IS_TYPE(r#type: u32, mask: u32) -> u32 {
+    r#type & 1
+}
@@ -0,0 +1,29 @@
+pub const ITEM = extern struct {
+    @"type": DWORD,
+    @"align": WORD,
+    @"error": LONG,
+    @"u8": BYTE,
+    name: LPCWSTR,
+};
+
+pub const VALUE = extern union {
+    @"fn": u32,
+};
+
+pub const KIND = enum(c_int) {
+    @"null" = 0,
+    @"test",
+};
+
+pub extern "user32" fn SetType (
+    @"type": DWORD,
+    callback: ?*const fn(@"struct": LPVOID) callconv(.C) void,
+) callconv(.C) void;
+
+pub fn IS_TYPE(
+    @"type": u32,
+    mask: u32,
+) u32 {
+    _ = mask;
+    return @"type" & 1;
+}
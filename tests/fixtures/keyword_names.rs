@@ -0,0 +1,18 @@
+pub const test: u32 = 1;
+pub const error: u32 = test + 1;
+pub static mut comptime: u32 = 0;
+pub type r#async = DWORD;
+pub type u7 = BYTE;
+pub type P = *mut r#async;
+pub type W = u16;
+STRUCT!{struct opaque {
+    size: u7,
+}}
+ENUM!{enum export {
+    A,
+}}
+DECLARE_HANDLE!{anyframe, r#struct}
+pub mod r#try {
+    pub const X: u32 = 1;
+}
+pub const Y: u32 = r#try::X;
@@ -0,0 +1,24 @@
+pub const @"test" = 1;
+pub const @"error" = @"test" + 1;
+pub var @"comptime": u32 = 0;
+pub const @"async" = DWORD;
+pub const @"u7" = BYTE;
+pub const P = ?*@"async";
+pub const W = u16;
+
+pub const @"opaque" = extern struct {
+    size: @"u7",
+};
+
+pub const @"export" = enum(c_int) {
+    A,
+};
+
+pub const @"struct" = opaque {};
+pub const @"anyframe" = ?*@"struct";
+
+pub const @"try" = struct {
+    pub const X = 1;
+};
+
+pub const Y = @"try".X;
@@ -0,0 +1,18 @@
+pub const LETTER: char = 'A';
+pub const TAB: char = '\t';
+pub const QUOTE: char = '\'';
+pub const BACKSLASH: char = '\\';
+pub const NUL: char = '\0';
+pub const COPYRIGHT: char = '©';
+pub const SNOWMAN: char = '\u{2603}';
+pub const PI: f64 = 3.14159;
+pub const HALF: f32 = 0.5f32;
+pub const THOUSAND: f64 = 1_000.0_f64;
+pub const ONE: f64 = 1.;
+pub const TWO: f32 = 2f32;
+pub const AVOGADRO: f64 = 6.022e23;
+pub const EPSILON: f32 = 1.1920929E-7_f32;
+pub const NEG: f64 = -2.5e-3;
+pub const ENABLED: bool = true;
+pub const DISABLED: bool = false;
+pub static TRACING: bool = false;
@@ -0,0 +1,18 @@
+pub const LETTER = 'A';
+pub const TAB = '\t';
+pub const QUOTE = '\'';
+pub const BACKSLASH = '\\';
+pub const NUL = '\x00';
+pub const COPYRIGHT = '\u{a9}';
+pub const SNOWMAN = '\u{2603}';
+pub const PI = 3.14159;
+pub const HALF = 0.5;
+pub const THOUSAND = 1_000.0;
+pub const ONE = 1.0;
+pub const TWO = 2.0;
+pub const AVOGADRO = 6.022e23;
+pub const EPSILON = 1.1920929E-7;
+pub const NEG = -2.5e-3;
+pub const ENABLED = true;
+pub const DISABLED = false;
+pub const TRACING: bool = false;
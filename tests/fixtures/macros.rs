@@ -0,0 +1,33 @@
+UNION!{union LARGE_INTEGER {
+    [i64; 1],
+    s s_mut: LARGE_INTEGER_s,
+    QuadPart QuadPart_mut: LONGLONG,
+}}
+ENUM!{enum D3DFORMAT {
+    D3DFMT_UNKNOWN = 0,
+    D3DFMT_R8G8B8 = 20,
+    D3DFMT_A8R8G8B8,
+}}
+FN!{stdcall TIMERPROC(
+    HWND,
+    UINT,
+    UINT_PTR,
+    DWORD,
+) -> ()}
+FN!{cdecl PFN_COMPARE(
+    *const c_void,
+    *const c_void,
+) -> c_int}
+DEFINE_GUID!{IID_IUnknown,
+    0x00000000, 0x0000, 0x0000, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46}
+RIDL!{#[uuid(0x00000001, 0x0000, 0x0000, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46)]
+interface IClassFactory(IClassFactoryVtbl): IUnknown(IUnknownVtbl) {
+    fn CreateInstance(
+        pUnkOuter: *mut IUnknown,
+        riid: REFIID,
+        ppvObject: *mut *mut c_void,
+    ) -> HRESULT,
+    fn LockServer(
+        fLock: BOOL,
+    ) -> HRESULT,
+}}
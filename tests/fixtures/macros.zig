@@ -0,0 +1,24 @@
+pub const LARGE_INTEGER = extern union {
+    s: LARGE_INTEGER_s,
+    QuadPart: LONGLONG,
+};
+
+pub const D3DFORMAT = enum(c_int) {
+    D3DFMT_UNKNOWN = 0,
+    D3DFMT_R8G8B8 = 20,
+    D3DFMT_A8R8G8B8,
+};
+
+pub const TIMERPROC = ?*const fn(HWND, UINT, UINT_PTR, DWORD) callconv(.C) void;
+pub const PFN_COMPARE = ?*const fn(?*const anyopaque, ?*const anyopaque) callconv(.C) c_int;
+pub const IID_IUnknown = GUID{ .Data1 = 0x00000000, .Data2 = 0x0000, .Data3 = 0x0000, .Data4 = .{ 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46 } };
+
+pub const IClassFactoryVtbl = extern struct {
+    parent: IUnknownVtbl,
+    CreateInstance: *const fn(This: *IClassFactory, pUnkOuter: ?*IUnknown, riid: REFIID, ppvObject: ?*?*anyopaque) callconv(.C) HRESULT,
+    LockServer: *const fn(This: *IClassFactory, fLock: c_int) callconv(.C) HRESULT,
+};
+pub const IClassFactory = extern struct {
+    lpVtbl: *const IClassFactoryVtbl,
+    pub const uuid = GUID{ .Data1 = 0x00000001, .Data2 = 0x0000, .Data3 = 0x0000, .Data4 = .{ 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46 } };
+};
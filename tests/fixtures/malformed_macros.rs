@@ -0,0 +1,9 @@
+STRUCT!{struct BROKEN {
+    a: u32
+    b: u32,
+}}
+STRUCT!{struct}
+ENUM!{enum E { A = }}
+STRUCT!{struct FINE {
+    a: u32,
+}}
@@ -0,0 +1,3 @@
+pub const FINE = extern struct {
+    a: u32,
+};
@@ -0,0 +1,3 @@
+pub const MASK: u32 = FLAGS.bits();
+pub const BOTH: u32 = A.union(B).bits();
+pub const SHIFTED: u32 = (1u32 << 4).rotate_left(2);
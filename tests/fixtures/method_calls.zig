@@ -0,0 +1,3 @@
+pub const MASK = FLAGS.bits();
+pub const BOTH = A.@"union"(B).bits();
+pub const SHIFTED = (1 << 4).rotate_left(2);
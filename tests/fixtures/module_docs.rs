@@ -0,0 +1,7 @@
+//! Window styles.
+//!
+//! Translated from `um/winuser.rs`.
+#![allow(non_camel_case_types)]
+use shared::minwindef::DWORD;
+/// The default style.
+pub const WS_OVERLAPPED: DWORD = 0x00000000;
@@ -0,0 +1,10 @@
+//! Window styles.
+//!
+//! Translated from `um/winuser.rs`.
+
+const shared = @import("shared.zig");
+
+const DWORD = shared.minwindef.DWORD;
+
+/// The default style.
+pub const WS_OVERLAPPED = 0x00000000;
@@ -0,0 +1,18 @@
+extern "system" {
+    /// Closes an open object handle.
+    #[must_use]
+    pub fn CloseHandle(
+        hObject: HANDLE,
+    ) -> BOOL;
+    #[must_use = "the handle leaks unless closed"]
+    pub fn CreateEventW(
+        lpName: LPCWSTR,
+    ) -> HANDLE;
+    pub fn Sleep(
+        dwMilliseconds: DWORD,
+    );
+}
+#[must_use]
+pub const fn HRESULT_CODE(hr: HRESULT) -> HRESULT {
+    hr & 0xffff
+}
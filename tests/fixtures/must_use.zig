@@ -0,0 +1,21 @@
+/// Closes an open object handle.
+/// Must use return value.
+pub extern "user32" fn CloseHandle (
+    hObject: HANDLE,
+) callconv(.C) c_int;
+
+/// Must use return value: the handle leaks unless closed
+pub extern "user32" fn CreateEventW (
+    lpName: LPCWSTR,
+) callconv(.C) HANDLE;
+
+pub extern "user32" fn Sleep (
+    dwMilliseconds: DWORD,
+) callconv(.C) void;
+
+/// Must use return value.
+pub fn HRESULT_CODE(
+    hr: HRESULT,
+) HRESULT {
+    return hr & 0xffff;
+}
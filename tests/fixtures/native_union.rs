@@ -0,0 +1,18 @@
+/// A large integer, as halves or whole.
+#[repr(C)]
+pub union LARGE_INTEGER {
+    pub s: LARGE_INTEGER_s,
+    /// The whole value.
+    pub QuadPart: LONGLONG,
+}
+const_assert!(size_of::<LARGE_INTEGER>() == 8);
+#[repr(C, align(16))]
+pub union ALIGNED {
+    pub a: u64,
+    pub b: [u8; 16],
+}
+#[repr(C, packed)]
+union PACKED {
+    a: u16,
+    b: u8,
+}
@@ -0,0 +1,19 @@
+/// A large integer, as halves or whole.
+pub const LARGE_INTEGER = extern union {
+    s: LARGE_INTEGER_s,
+    /// The whole value.
+    QuadPart: LONGLONG,
+};
+comptime {
+    @import("std").debug.assert(@sizeOf(LARGE_INTEGER) == 8);
+}
+
+pub const ALIGNED = extern union {
+    a: u64 align(16),
+    b: [16]u8,
+};
+
+const PACKED = extern union {
+    a: u16 align(1),
+    b: u8 align(1),
+};
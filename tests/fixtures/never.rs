@@ -0,0 +1,6 @@
+extern "system" {
+    pub fn ExitProcess(
+        uExitCode: UINT,
+    ) -> !;
+}
+pub type PFN_EXIT = Option<unsafe extern "system" fn(code: UINT) -> !>;
@@ -0,0 +1,5 @@
+pub extern "user32" fn ExitProcess (
+    uExitCode: UINT,
+) callconv(.C) noreturn;
+
+pub const PFN_EXIT = ?*const fn(code: UINT) callconv(.C) noreturn;
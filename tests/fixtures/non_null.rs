@@ -0,0 +1,4 @@
+pub type PBYTE_NN = NonNull<u8>;
+pub type PVOID_NN = NonNull<c_void>;
+pub type PPWSTR_NN = NonNull<*mut u16>;
+pub type PBYTE_OPT = Option<NonNull<u8>>;
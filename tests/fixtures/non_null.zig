@@ -0,0 +1,4 @@
+pub const PBYTE_NN = *u8;
+pub const PVOID_NN = *anyopaque;
+pub const PPWSTR_NN = *?*u16;
+pub const PBYTE_OPT = ?*u8;
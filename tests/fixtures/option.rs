@@ -0,0 +1,7 @@
+pub type PVOID_OPT = Option<*mut c_void>;
+pub type PCWSTR_OPT = Option<*const u16>;
+pub type CALLBACK = Option<extern "system" fn() -> i32>;
+pub type WNDPROC = Option<unsafe extern "system" fn(HWND, UINT, WPARAM, LPARAM) -> LRESULT>;
+pub type RECT_OPT = Option<&RECT>;
+pub type RECT_MUT_OPT = Option<&mut RECT>;
+pub type NOT_POINTER = Option<u32>;
@@ -0,0 +1,6 @@
+pub const PVOID_OPT = ?*anyopaque;
+pub const PCWSTR_OPT = ?*const u16;
+pub const CALLBACK = ?*const fn() callconv(.C) i32;
+pub const WNDPROC = ?*const fn(HWND, UINT, WPARAM, LPARAM) callconv(.C) LRESULT;
+pub const RECT_OPT = ?*const RECT;
+pub const RECT_MUT_OPT = ?*RECT;
@@ -0,0 +1,3 @@
+pub type A = (u32);
+pub type B = *mut (LONG);
+pub type C = [(u8); 4];
@@ -0,0 +1,3 @@
+pub const A = u32;
+pub const B = ?*LONG;
+pub const C = [4]u8;
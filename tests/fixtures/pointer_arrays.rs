@@ -0,0 +1,10 @@
+pub type PGUID_DATA4 = *const [u8; 8];
+pub type PMUT_DATA = *mut [u16; MAX_PATH];
+pub type PPDATA = *mut *const [u8; 8];
+pub type PPTRS = *const [*mut c_void; 4];
+pub type PGRID = *mut [[u8; 3]; 3];
+extern "system" {
+    pub fn GetData4(
+        data: *mut [u8; 8],
+    ) -> *const [u8; 8];
+}
@@ -0,0 +1,9 @@
+pub const PGUID_DATA4 = ?*const [8]u8;
+pub const PMUT_DATA = ?*[MAX_PATH]u16;
+pub const PPDATA = ?*?*const [8]u8;
+pub const PPTRS = ?*const [4]?*anyopaque;
+pub const PGRID = ?*[3][3]u8;
+
+pub extern "user32" fn GetData4 (
+    data: ?*[8]u8,
+) callconv(.C) ?*const [8]u8;
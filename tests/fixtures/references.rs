@@ -0,0 +1,10 @@
+pub type PCRECT = &RECT;
+pub type PRECT = &mut RECT;
+pub type PCBYTES = &[u8];
+pub type PPCWSTR = &*const u16;
+pub type PCBYTE = &u8;
+pub type PBYTES = &mut [u8];
+pub type PBYTE = &mut u8;
+pub type PCWCHARS = *const [u16];
+pub type PWCHARS = *mut [u16];
+pub type PPWCHARS = *mut *const [u16];
@@ -0,0 +1,10 @@
+pub const PCRECT = *const RECT;
+pub const PRECT = *RECT;
+pub const PCBYTES = []const u8;
+pub const PPCWSTR = *const ?*const u16;
+pub const PCBYTE = *const u8;
+pub const PBYTES = []u8;
+pub const PBYTE = *u8;
+pub const PCWCHARS = ?[*]const u16;
+pub const PWCHARS = ?[*]u16;
+pub const PPWCHARS = ?*?[*]const u16;
@@ -0,0 +1,8 @@
+pub const IDC_ARROW: LPCWSTR = MAKEINTRESOURCE!(32512);
+pub const IDI_APPLICATION: LPCWSTR = MAKEINTRESOURCEW!(32512);
+pub const RT_CURSOR: LPSTR = MAKEINTRESOURCEA!(1);
+pub const RT_ICON: LPCWSTR = MAKEINTRESOURCE!(RT_ICON_ID);
+pub const RT_GROUP_CURSOR: LPCWSTR = MAKEINTRESOURCE!(RT_CURSOR_ID + DIFFERENCE);
+pub const WC_DIALOG: LPWSTR = MAKEINTATOM!(0x8002);
+pub static IDC_HAND: *const u16 = MAKEINTRESOURCE!(32649);
+pub const CURSORS: CURSOR_IDS = CURSOR_IDS { arrow: MAKEINTRESOURCE!(32512) };
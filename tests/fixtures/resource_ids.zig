@@ -0,0 +1,8 @@
+pub const IDC_ARROW = @as(LPCWSTR, @ptrFromInt(32512));
+pub const IDI_APPLICATION = @as(LPCWSTR, @ptrFromInt(32512));
+pub const RT_CURSOR = @as(LPSTR, @ptrFromInt(1));
+pub const RT_ICON = @as(LPCWSTR, @ptrFromInt(RT_ICON_ID));
+pub const RT_GROUP_CURSOR = @as(LPCWSTR, @ptrFromInt(RT_CURSOR_ID + DIFFERENCE));
+pub const WC_DIALOG = @as(LPWSTR, @ptrFromInt(0x8002));
+pub const IDC_HAND: ?*const u16 = @as(?*const u16, @ptrFromInt(32649));
+pub const CURSORS = CURSOR_IDS{ .arrow = @as(LPWSTR, @ptrFromInt(32512)) };
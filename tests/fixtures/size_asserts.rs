@@ -0,0 +1,16 @@
+STRUCT!{struct POINT {
+    x: LONG,
+    y: LONG,
+}}
+const_assert!(size_of::<POINT>() == 8);
+pub mod inner {
+    STRUCT!{struct SIZE {
+        cx: LONG,
+        cy: LONG,
+    }}
+    const_assert!(8 == ::core::mem::size_of::<SIZE>());
+}
+STRUCT!{struct RECT {
+    left: LONG,
+}}
+const_assert!(align_of::<RECT>() == 4);
@@ -0,0 +1,21 @@
+pub const POINT = extern struct {
+    x: LONG,
+    y: LONG,
+};
+comptime {
+    @import("std").debug.assert(@sizeOf(POINT) == 8);
+}
+
+pub const inner = struct {
+    pub const SIZE = extern struct {
+        cx: LONG,
+        cy: LONG,
+    };
+    comptime {
+        @import("std").debug.assert(@sizeOf(SIZE) == 8);
+    }
+};
+
+pub const RECT = extern struct {
+    left: LONG,
+};
@@ -0,0 +1,12 @@
+pub const A: u32 = 1;
+pub const B: u32 = 2;
+extern "system" {
+    pub fn GetLastError() -> DWORD;
+    pub fn SetLastError(
+        dwErrCode: DWORD,
+    );
+    pub fn Sleep(
+        dwMilliseconds: DWORD,
+    );
+}
+pub const C: u32 = 3;
@@ -0,0 +1,15 @@
+pub const A = 1;
+pub const B = 2;
+
+pub extern "user32" fn GetLastError (
+) callconv(.C) DWORD;
+
+pub extern "user32" fn SetLastError (
+    dwErrCode: DWORD,
+) callconv(.C) void;
+
+pub extern "user32" fn Sleep (
+    dwMilliseconds: DWORD,
+) callconv(.C) void;
+
+pub const C = 3;
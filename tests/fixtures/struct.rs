@@ -0,0 +1,25 @@
+STRUCT!{#[debug] struct POINT {
+    x: LONG,
+    y: LONG,
+}}
+STRUCT!{struct RECT {
+    left: LONG,
+    top: LONG,
+    right: LONG,
+    bottom: LONG,
+}}
+STRUCT!{struct MSG {
+    hwnd: HWND,
+    message: UINT,
+    wParam: WPARAM,
+    lParam: LPARAM,
+    time: DWORD,
+    pt: POINT,
+}}
+STRUCT!{#[repr(packed)] struct BITMAPFILEHEADER {
+    bfType: WORD,
+    bfSize: DWORD,
+    bfReserved1: WORD,
+    bfReserved2: WORD,
+    bfOffBits: DWORD,
+}}
@@ -0,0 +1,28 @@
+pub const POINT = extern struct {
+    x: LONG,
+    y: LONG,
+};
+
+pub const RECT = extern struct {
+    left: LONG,
+    top: LONG,
+    right: LONG,
+    bottom: LONG,
+};
+
+pub const MSG = extern struct {
+    hwnd: HWND,
+    message: UINT,
+    wParam: WPARAM,
+    lParam: LPARAM,
+    time: DWORD,
+    pt: POINT,
+};
+
+pub const BITMAPFILEHEADER = extern struct {
+    bfType: WORD align(1),
+    bfSize: DWORD align(1),
+    bfReserved1: WORD align(1),
+    bfReserved2: WORD align(1),
+    bfOffBits: DWORD align(1),
+};
@@ -0,0 +1,17 @@
+STRUCT!{
+/// A point with `SHORT` coordinates.
+#[repr(C)]
+struct POINTS {
+    /// The x coordinate.
+    x: SHORT,
+    y: SHORT,
+}}
+STRUCT!{#[debug] #[repr(align(16))] struct M128A {
+    Low: ULONGLONG,
+    High: LONGLONG,
+}}
+STRUCT!{#[inline] struct BROKEN {
+    x: SHORT
+    y: SHORT
+}}
+pub const AFTER: u32 = 1;
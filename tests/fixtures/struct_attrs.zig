@@ -0,0 +1,13 @@
+/// A point with `SHORT` coordinates.
+pub const POINTS = extern struct {
+    /// The x coordinate.
+    x: SHORT,
+    y: SHORT,
+};
+
+pub const M128A = extern struct {
+    Low: ULONGLONG align(16),
+    High: LONGLONG,
+};
+
+pub const AFTER = 1;
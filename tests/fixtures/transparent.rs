@@ -0,0 +1,16 @@
+STRUCT!{
+/// A strongly typed handle.
+#[repr(transparent)]
+struct Wrapper(pub HANDLE);}
+STRUCT!{#[repr(transparent)] struct Flags(u32);}
+STRUCT!{#[repr(transparent)] struct Named {
+    value: u32,
+}}
+STRUCT!{#[repr(transparent)] struct Marked {
+    value: u32,
+    _marker: PhantomData<*mut u8>,
+}}
+STRUCT!{#[repr(transparent)] struct TwoFields {
+    a: u32,
+    b: u32,
+}}
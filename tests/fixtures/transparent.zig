@@ -0,0 +1,6 @@
+/// A strongly typed handle.
+pub const Wrapper = HANDLE;
+
+pub const Flags = u32;
+pub const Named = u32;
+pub const Marked = u32;
@@ -0,0 +1,7 @@
+STRUCT!{struct POINT_PAIR(POINT, POINT);}
+STRUCT!{#[repr(packed)] struct PACKED_PAIR(
+    /// The low half.
+    WORD,
+    PhantomData<u8>,
+    DWORD,
+);}
@@ -0,0 +1,10 @@
+pub const POINT_PAIR = extern struct {
+    @"0": POINT,
+    @"1": POINT,
+};
+
+pub const PACKED_PAIR = extern struct {
+    /// The low half.
+    @"0": WORD align(1),
+    @"2": DWORD align(1),
+};
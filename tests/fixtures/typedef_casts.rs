@@ -0,0 +1,12 @@
+use shared::minwindef::{DWORD, HKEY};
+use shared::ntdef::HANDLE;
+pub const INVALID_HANDLE_VALUE: HANDLE = -1isize as HANDLE;
+pub const HKEY_CLASSES_ROOT: HKEY = 0x80000000 as HKEY;
+pub const FIVE: DWORD = 5 as DWORD;
+pub const CW_USEDEFAULT: c_int = 0x80000000u32 as c_int;
+pub const ALL_BITS: u32 = -1i32 as u32;
+pub const LOW_BYTE: u8 = 0x1234u16 as u8;
+pub const SIGN_EXTENDED: u64 = -2i32 as u64;
+pub const POPUP: DWORD = 0x80000000;
+pub const POPUP_INT: c_int = POPUP as c_int;
+pub const UNKNOWN: MYTYPE = 1 as MYTYPE;
@@ -0,0 +1,15 @@
+const shared = @import("shared.zig");
+
+const DWORD = shared.minwindef.DWORD;
+const HKEY = shared.minwindef.HKEY;
+
+const HANDLE = shared.ntdef.HANDLE;
+pub const INVALID_HANDLE_VALUE = @as(HANDLE, @ptrFromInt(@as(usize, @bitCast(@as(isize, -1)))));
+pub const HKEY_CLASSES_ROOT = @as(HKEY, @ptrFromInt(0x80000000));
+pub const FIVE = @as(DWORD, 5);
+pub const CW_USEDEFAULT = @as(c_int, @bitCast(@as(u32, 0x80000000)));
+pub const ALL_BITS = @as(u32, @bitCast(@as(i32, -1)));
+pub const LOW_BYTE = @as(u8, @truncate(@as(u16, 0x1234)));
+pub const SIGN_EXTENDED = @as(u64, @bitCast(@as(i64, @as(i32, -2))));
+pub const POPUP = 0x80000000;
+pub const POPUP_INT = @as(c_int, @bitCast(@as(u32, POPUP)));
@@ -0,0 +1,23 @@
+use ctypes::{c_char, c_int, c_void, wchar_t};
+pub type BYTE = c_uchar;
+pub type CHAR = c_char;
+pub type SHORT = c_short;
+pub type LONG = c_long;
+pub type ULONGLONG = __uint64;
+pub type FLOAT = c_float;
+pub type WCHAR = wchar_t;
+pub type SIZE_T = usize;
+pub type LPWSTR = *mut WCHAR;
+pub type LPCWSTR = *const WCHAR;
+pub type PVOID = *mut c_void;
+pub type LPCVOID = *const c_void;
+pub type PPVOID = *mut *mut c_void;
+pub type PATHBUF = [wchar_t; 260];
+pub type WNDPROC = unsafe extern "system" fn(hwnd: HWND, uMsg: UINT) -> LRESULT;
+pub type PFN = extern "C" fn(*mut c_void);
+pub type Pair = (u32, u32);
+#[cfg(target_pointer_width = "32")]
+pub type ULONG_PTR = u32;
+#[cfg(target_pointer_width = "64")]
+pub type ULONG_PTR = u64;
+pub type SSIZE_T = isize;
@@ -0,0 +1,19 @@
+pub const BYTE = u8;
+pub const CHAR = i8;
+pub const SHORT = c_short;
+pub const LONG = c_long;
+pub const ULONGLONG = u64;
+pub const FLOAT = f32;
+pub const WCHAR = u16;
+pub const SIZE_T = usize;
+pub const LPWSTR = ?*WCHAR;
+pub const LPCWSTR = ?*const WCHAR;
+pub const PVOID = ?*anyopaque;
+pub const LPCVOID = ?*const anyopaque;
+pub const PPVOID = ?*?*anyopaque;
+pub const PATHBUF = [260]u16;
+pub const WNDPROC = ?*const fn(hwnd: HWND, uMsg: UINT) callconv(.C) LRESULT;
+pub const PFN = ?*const fn(?*anyopaque) callconv(.C) void;
+pub const Pair = struct { u32, u32 };
+pub const ULONG_PTR = u64;
+pub const SSIZE_T = isize;
@@ -0,0 +1,8 @@
+pub const TABLE: [DWORD; 2] = [1, 2];
+pub const IDX: DWORD = TABLE[0];
+pub static PAIR: (DWORD, DWORD) = (1, 2);
+pub enum SIZES {
+    Small = 1,
+    Large = TABLE[1],
+}
+pub const AFTER: DWORD = 3;
@@ -0,0 +1,2 @@
+pub const TABLE: [2]DWORD = [_]DWORD{ 1, 2 };
+pub const AFTER = 3;
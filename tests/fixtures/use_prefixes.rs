@@ -0,0 +1,19 @@
+use crate::shared::minwindef::DWORD;
+use ::shared::basetsd::UINT_PTR;
+use crate::um::{self, winnt::HANDLE};
+use self::inner::POINT as INNER_POINT;
+use self::LIMIT;
+pub const LIMIT: u32 = 10;
+pub mod inner {
+    use super::LIMIT;
+    use super::LIMIT as MAX;
+    use super::super::far::Far;
+    use self::deeper::Z as Y;
+    pub type POINT = u32;
+    pub mod deeper {
+        use super::super::LIMIT;
+        use super::super::um::winnt::LONG;
+        pub const Z: u32 = 1;
+    }
+}
+use super::Parent;
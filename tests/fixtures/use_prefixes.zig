@@ -0,0 +1,19 @@
+const shared = @import("shared.zig");
+const um = @import("um.zig");
+
+const DWORD = shared.minwindef.DWORD;
+const UINT_PTR = shared.basetsd.UINT_PTR;
+const HANDLE = um.winnt.HANDLE;
+const INNER_POINT = inner.POINT;
+pub const LIMIT = 10;
+
+pub const inner = struct {
+    const MAX = LIMIT;
+    const Y = deeper.Z;
+    pub const POINT = u32;
+
+    pub const deeper = struct {
+        const LONG = um.winnt.LONG;
+        pub const Z = 1;
+    };
+};
@@ -0,0 +1,7 @@
+pub const PUBLIC: u32 = 1;
+pub(crate) const CRATE: u32 = 2;
+crate const CRATE_KEYWORD: u32 = 3;
+pub(super) const SUPER: u32 = 4;
+pub(in crate::um) const IN_PATH: u32 = 5;
+pub(self) const SELF: u32 = 6;
+const PRIVATE: u32 = 7;
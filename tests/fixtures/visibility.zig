@@ -0,0 +1,7 @@
+pub const PUBLIC = 1;
+pub const CRATE = 2;
+pub const CRATE_KEYWORD = 3;
+pub const SUPER = 4;
+pub const IN_PATH = 5;
+const SELF = 6;
+const PRIVATE = 7;
@@ -0,0 +1,18 @@
+//! Falling back to the unformatted output when `--fmt` can't run `zig fmt`.
+
+use std::process::Command;
+
+#[test]
+fn without_zig() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/literals.rs");
+    let output = Command::new(env!("CARGO_BIN_EXE_winapi-zig"))
+        .args(["--no-header", "--fmt", fixture])
+        .env("PATH", "")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("warning: unable to run `zig fmt`"), "{}", stderr);
+    let expected = include_str!("fixtures/literals.zig");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+}
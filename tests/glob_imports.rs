@@ -0,0 +1,41 @@
+//! Glob imports, left as a comment or translated with
+//! `--glob-as-usingnamespace`.
+
+use std::process::Command;
+
+use winapi_zig::{convert_str, convert_str_with_options, Options};
+
+const SOURCE: &str = "
+use um::winnt::*;
+pub use shared::minwindef::*;
+";
+
+#[test]
+fn comment_by_default() {
+    let zig = convert_str(SOURCE).unwrap().zig;
+    assert!(zig.contains("// Glob import of um.winnt can't be expanded.\n"), "{}", zig);
+    assert!(!zig.contains("usingnamespace"), "{}", zig);
+}
+
+#[test]
+fn usingnamespace() {
+    let options = Options {
+        glob_as_usingnamespace: true,
+        ..Options::default()
+    };
+    let zig = convert_str_with_options(SOURCE, &options).unwrap().zig;
+    assert!(zig.contains("\nusingnamespace um.winnt;\n"), "{}", zig);
+    assert!(zig.contains("\npub usingnamespace shared.minwindef;\n"), "{}", zig);
+}
+
+#[test]
+fn flag() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/items.rs");
+    let output = Command::new(env!("CARGO_BIN_EXE_winapi-zig"))
+        .args(["--no-header", "--glob-as-usingnamespace", fixture])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("\nusingnamespace um.winnt;\n"), "{}", stdout);
+}
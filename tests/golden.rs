@@ -0,0 +1,89 @@
+//! Golden-file tests.
+//!
+//! Each `fixtures/*.rs` file is translated and compared against the `.zig`
+//! file beside it, and its diagnostics against the `.diagnostics` file, which
+//! is left out when there are none. Run with `UPDATE_GOLDEN=1` to regenerate
+//! both after an intended change in output.
+//!
+//! With `ZIG_AST_CHECK=1`, each translation is also checked by `zig ast-check`,
+//! which needs `zig` on the `PATH`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use winapi_zig::convert_file;
+
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut fixtures: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    fixtures.sort();
+    fixtures
+}
+
+/// Compare `actual` against the golden file at `path`, or overwrite it when
+/// updating. A missing golden file stands for empty output.
+fn check(path: &Path, actual: &str, update: bool, failures: &mut Vec<PathBuf>) {
+    if update {
+        if actual.is_empty() {
+            let _ = fs::remove_file(path);
+        } else {
+            fs::write(path, actual).unwrap();
+        }
+        return;
+    }
+    let expected = fs::read_to_string(path).unwrap_or_default();
+    if actual != expected {
+        eprintln!("--- expected {}\n{}", path.display(), expected);
+        eprintln!("+++ actual\n{}", actual);
+        failures.push(path.to_owned());
+    }
+}
+
+/// Check that `zig` is valid Zig source with `zig ast-check`, returning its
+/// errors if not.
+fn ast_check(fixture: &Path, zig: &str) -> Result<(), String> {
+    let dir = env::temp_dir().join("winapi-zig-golden");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(fixture.with_extension("zig").file_name().unwrap());
+    fs::write(&path, zig).unwrap();
+    let output = match Command::new("zig").arg("ast-check").arg(&path).output() {
+        Ok(output) => output,
+        Err(error) => panic!("unable to run `zig ast-check`: {}", error),
+    };
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).into_owned())
+    }
+}
+
+#[test]
+fn golden() {
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+    let check_zig = env::var_os("ZIG_AST_CHECK").is_some();
+    let mut failures = Vec::new();
+    for fixture in fixtures() {
+        let translation = match convert_file(&fixture) {
+            Ok(translation) => translation,
+            Err(error) => panic!("{}: {}", fixture.display(), error),
+        };
+        check(&fixture.with_extension("zig"), &translation.zig, update, &mut failures);
+        let diagnostics: String =
+            translation.diagnostics.iter().map(|d| format!("{}\n", d)).collect();
+        let path = fixture.with_extension("diagnostics");
+        check(&path, &diagnostics, update, &mut failures);
+        if check_zig {
+            if let Err(errors) = ast_check(&fixture, &translation.zig) {
+                eprintln!("zig ast-check of {} failed:\n{}", fixture.display(), errors);
+                failures.push(fixture);
+            }
+        }
+    }
+    assert!(failures.is_empty(), "output differs from {:?}", failures);
+}
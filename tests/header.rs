@@ -0,0 +1,35 @@
+//! The generated-file banner, and leaving it out with `--no-header`.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn fixture() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/literals.rs")
+}
+
+fn translate(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_winapi-zig"))
+        .args(args)
+        .arg(fixture())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn banner() {
+    let banner = format!(
+        "// Generated by winapi-zig from {}.\n\
+         // Do not edit: changes will be lost when it is regenerated.\n\n",
+        fixture().display()
+    );
+    let stdout = translate(&[]);
+    assert!(stdout.starts_with(&banner), "{}", stdout);
+}
+
+#[test]
+fn no_header() {
+    let expected = include_str!("fixtures/literals.zig");
+    assert_eq!(translate(&["--no-header"]), expected);
+}
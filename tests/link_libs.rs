@@ -0,0 +1,36 @@
+//! Choosing the library for extern blocks by module.
+
+use std::path::Path;
+
+use winapi_zig::{convert_file_with_options, parse_link_lib_map, Error, Options};
+
+fn convert(map: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/link_libs/processthreadsapi.rs");
+    let options = Options {
+        link_libs: parse_link_lib_map(map).unwrap(),
+        ..Options::default()
+    };
+    convert_file_with_options(&path, &options).unwrap().zig
+}
+
+#[test]
+fn by_module() {
+    let zig = convert("winuser = \"user32\"\nprocessthreadsapi = \"kernel32\"\n");
+    assert!(zig.contains("pub extern \"kernel32\" fn GetCurrentProcessId ("));
+    // `#[link]` still takes precedence.
+    assert!(zig.contains("pub extern \"ntdll\" fn NtGetCurrentProcessorNumber ("));
+}
+
+#[test]
+fn unmapped() {
+    let zig = convert("winuser = \"user32\"");
+    assert!(zig.contains("pub extern \"user32\" fn GetCurrentProcessId ("));
+}
+
+#[test]
+fn malformed() {
+    match parse_link_lib_map("winuser = user32") {
+        Err(Error::LinkLibMap(1, _)) => (),
+        result => panic!("parsed as {:?}", result),
+    }
+}
@@ -0,0 +1,7 @@
+extern "system" {
+    pub fn GetCurrentProcessId() -> DWORD;
+}
+#[link(name = "ntdll")]
+extern "system" {
+    pub fn NtGetCurrentProcessorNumber() -> ULONG;
+}
@@ -0,0 +1,37 @@
+//! Imports of submodules from a directory per top-level module.
+
+use winapi_zig::{convert_str_with_options, Options};
+
+const SOURCE: &str = r#"
+use shared::minwindef::DWORD;
+use um::winuser::{self, HWND};
+use um::winnt::HANDLE as H;
+use km::wdm;
+"#;
+
+#[test]
+fn nested() {
+    let mut options = Options::default();
+    options.module_dirs.insert("um".into());
+    options.module_dirs.insert("km".into());
+    let zig = convert_str_with_options(SOURCE, &options).unwrap().zig;
+    assert_eq!(
+        zig,
+        r#"const shared = @import("shared.zig");
+const wdm = @import("km/wdm.zig");
+const winnt = @import("um/winnt.zig");
+const winuser = @import("um/winuser.zig");
+
+const DWORD = shared.minwindef.DWORD;
+const HWND = winuser.HWND;
+const H = winnt.HANDLE;
+"#
+    );
+}
+
+#[test]
+fn flat() {
+    let zig = convert_str_with_options(SOURCE, &Options::default()).unwrap().zig;
+    assert!(zig.contains("const um = @import(\"um.zig\");\n"));
+    assert!(zig.contains("const HWND = um.winuser.HWND;\n"));
+}
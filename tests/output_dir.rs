@@ -0,0 +1,101 @@
+//! Translating several inputs into an output directory.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// A fresh copy under the temp directory of a tree with winapi's layout,
+/// with `um/mod.rs` and `shared/mod.rs` next to files in those directories,
+/// and a `legacy/um.rs` that's another module `um`.
+fn source_tree(name: &str) -> PathBuf {
+    let root = env::temp_dir().join("winapi-zig-output-dir").join(name);
+    let _ = fs::remove_dir_all(&root);
+    let src = root.join("src");
+    for (path, source) in [
+        ("um/mod.rs", "pub mod winuser;\n"),
+        ("shared/mod.rs", "pub mod minwindef;\n"),
+        ("um/winuser.rs", "use shared::minwindef::DWORD;\npub type COLORREF = DWORD;\n"),
+        ("shared/minwindef.rs", "pub type DWORD = u32;\n"),
+        ("legacy/um.rs", "pub type DWORD = u32;\n"),
+    ] {
+        let path = src.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, source).unwrap();
+    }
+    root
+}
+
+fn run(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_winapi-zig"))
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn keeps_relative_paths() {
+    let root = source_tree("relative");
+    let inputs = ["um/mod.rs", "shared/mod.rs", "um/winuser.rs", "shared/minwindef.rs"];
+    let mut args = vec!["--no-header", "-o", "../out"];
+    args.extend(inputs);
+    let output = run(&root.join("src"), &args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let out = root.join("out");
+    let um = fs::read_to_string(out.join("um/mod.zig")).unwrap();
+    assert_eq!(um, "pub const winuser = @import(\"winuser.zig\");\n");
+    let shared = fs::read_to_string(out.join("shared/mod.zig")).unwrap();
+    assert_eq!(shared, "pub const minwindef = @import(\"minwindef.zig\");\n");
+    assert!(out.join("um/winuser.zig").is_file());
+    assert!(out.join("shared/minwindef.zig").is_file());
+}
+
+#[test]
+fn refuses_to_overwrite() {
+    let root = source_tree("overwrite");
+    let output = run(&root.join("src"), &["-o", "../out", "um/mod.rs", "um/mod.rs"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("was already written from another input"), "{}", stderr);
+    // The inputs are both in `um`, so that's where their paths start.
+    assert!(root.join("out/mod.zig").is_file());
+}
+
+#[test]
+fn build_zig_modules() {
+    let root = source_tree("build");
+    let args = [
+        "--module-dir",
+        "um",
+        "--module-dir",
+        "shared",
+        "--emit-build-zig",
+        "-o",
+        "../out",
+        "um/mod.rs",
+        "shared/mod.rs",
+        "um/winuser.rs",
+        "shared/minwindef.rs",
+    ];
+    let output = run(&root.join("src"), &args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let build = fs::read_to_string(root.join("out/build.zig")).unwrap();
+    assert!(build.contains(
+        "    _ = b.addModule(\"um\", .{ .source_file = .{ .path = \"um/mod.zig\" } });\n"
+    ));
+    assert!(build.contains("    _ = b.addModule(\"shared\", "));
+    assert!(build.contains(
+        "    winuser.dependencies.put(\"shared/minwindef.zig\", minwindef) catch @panic(\"OOM\");\n"
+    ));
+}
+
+#[test]
+fn duplicate_module_names() {
+    let root = source_tree("duplicate");
+    let args = ["--emit-build-zig", "-o", "../out", "um/mod.rs", "legacy/um.rs"];
+    let output = run(&root.join("src"), &args);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("legacy/um.rs: Module um is already another input"), "{}", stderr);
+}
@@ -0,0 +1,45 @@
+//! Writing a single input's translation to the `-o` file.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join("winapi-zig-output-file").join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn run(input: &Path, output: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_winapi-zig"))
+        .arg("--no-header")
+        .arg("-o")
+        .arg(output)
+        .arg(input)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn writes_the_file() {
+    let dir = dir("writes");
+    let (input, output) = (dir.join("a.rs"), dir.join("a.zig"));
+    fs::write(&input, "pub type DWORD = u32;\n").unwrap();
+    let result = run(&input, &output);
+    assert!(result.status.success());
+    assert!(result.stdout.is_empty());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "pub const DWORD = u32;\n");
+}
+
+#[test]
+fn keeps_the_old_output_on_failure() {
+    let dir = dir("keeps");
+    let (input, output) = (dir.join("a.rs"), dir.join("a.zig"));
+    fs::write(&input, "pub type DWORD = ;\n").unwrap();
+    fs::write(&output, "pub const DWORD = u32;\n").unwrap();
+    let result = run(&input, &output);
+    assert!(!result.status.success());
+    assert_eq!(fs::read_to_string(&output).unwrap(), "pub const DWORD = u32;\n");
+}
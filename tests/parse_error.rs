@@ -0,0 +1,32 @@
+//! Rendering a parse error with its location.
+
+use std::env;
+use std::fs;
+
+use winapi_zig::{convert_file, convert_str};
+
+const SOURCE: &str = "pub const A: u32 = 1;\npub const B: u32 = ;\n";
+
+#[test]
+fn points_at_the_offending_token() {
+    let error = convert_str(SOURCE).unwrap_err();
+    let expected = [
+        "Unable to parse file: expected expression",
+        " --> 2:20",
+        "  |",
+        "2 | pub const B: u32 = ;",
+        "  |                    ^",
+    ];
+    assert_eq!(error.to_string(), expected.join("\n"));
+}
+
+#[test]
+fn names_the_file() {
+    let dir = env::temp_dir().join("winapi-zig-parse-error");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("broken.rs");
+    fs::write(&path, SOURCE).unwrap();
+    let error = convert_file(&path).unwrap_err().to_string();
+    let location = format!(" --> {}:2:20\n", path.display());
+    assert!(error.contains(&location), "{}", error);
+}
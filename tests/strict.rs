@@ -0,0 +1,39 @@
+//! Failing on untranslated items with `--strict`.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run_strict(fixture: &str) -> Output {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(fixture);
+    Command::new(env!("CARGO_BIN_EXE_winapi-zig"))
+        .arg("--strict")
+        .arg(&fixture)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn fully_handled_succeeds() {
+    let output = run_strict("literals.rs");
+    assert!(output.status.success());
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn lossy_translations_succeed() {
+    let output = run_strict("box_vec.rs");
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Translated lossily"));
+    assert!(!stderr.contains("not translated"));
+}
+
+#[test]
+fn unhandled_items_fail() {
+    let output = run_strict("bitfield.rs");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("item(s) not translated in total"));
+    // The handled items are still written.
+    assert!(!output.stdout.is_empty());
+}
@@ -0,0 +1,73 @@
+//! Translation of the same source for different targets.
+
+use winapi_zig::{convert_str_with_options, Options, Target};
+
+const SOURCE: &str = r#"
+extern "system" {
+    pub fn GetTickCount() -> DWORD;
+}
+#[cfg(target_pointer_width = "32")]
+pub type ULONG_PTR = u32;
+#[cfg(target_pointer_width = "64")]
+pub type ULONG_PTR = u64;
+#[cfg(target_arch = "x86")]
+pub const ARCH: &str = "x86";
+#[cfg(target_arch = "x86_64")]
+pub const ARCH: &str = "x86_64";
+"#;
+
+fn convert_for(target: &str) -> String {
+    let options = Options {
+        target: Target::parse(target).unwrap(),
+        ..Options::default()
+    };
+    convert_str_with_options(SOURCE, &options).unwrap().zig
+}
+
+#[test]
+fn x86_64() {
+    let zig = convert_for("x86_64-windows");
+    assert_eq!(zig, convert_str_with_options(SOURCE, &Options::default()).unwrap().zig);
+    assert!(zig.contains(") callconv(.C) DWORD;"));
+    assert!(zig.contains("pub const ULONG_PTR = u64;"));
+    assert!(zig.contains("pub const ARCH = \"x86_64\";"));
+    assert!(!zig.contains("u32"));
+}
+
+#[test]
+fn i686() {
+    let zig = convert_for("i686-pc-windows-msvc");
+    assert!(zig.contains(") callconv(.Stdcall) DWORD;"));
+    assert!(zig.contains("pub const ULONG_PTR = u32;"));
+    assert!(zig.contains("pub const ARCH = \"x86\";"));
+    assert!(!zig.contains("u64"));
+}
+
+#[test]
+fn mixed_abis() {
+    let source = r#"
+extern "C" {
+    pub fn strlen(s: *const c_char) -> size_t;
+}
+extern "system" {
+    pub fn GetTickCount() -> DWORD;
+}
+extern {
+    pub fn abs(n: c_int) -> c_int;
+}
+"#;
+    let options = Options {
+        target: Target::parse("i686-windows").unwrap(),
+        ..Options::default()
+    };
+    let zig = convert_str_with_options(source, &options).unwrap().zig;
+    assert!(zig.contains(") callconv(.C) size_t;"));
+    assert!(zig.contains(") callconv(.Stdcall) DWORD;"));
+    assert!(zig.contains(") callconv(.C) c_int;"));
+}
+
+#[test]
+fn unknown() {
+    assert!(Target::parse("x86_64-linux").is_none());
+    assert!(Target::parse("mips-windows").is_none());
+}
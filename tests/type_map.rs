@@ -0,0 +1,39 @@
+//! Overriding the built-in type translations with a type map.
+
+use std::fs;
+use std::path::Path;
+
+use winapi_zig::{convert_str_with_options, parse_type_map, Error, Options};
+
+const SOURCE: &str = "
+pub type A = DWORD;
+pub type B = *mut LONG_PTR;
+pub type C = BOOL;
+pub type D = WORD;
+";
+
+#[test]
+fn overrides() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/type_map.toml");
+    let options = Options {
+        type_map: parse_type_map(&fs::read_to_string(path).unwrap()).unwrap(),
+        ..Options::default()
+    };
+    let zig = convert_str_with_options(SOURCE, &options).unwrap().zig;
+    assert!(zig.contains("pub const A = u32;"));
+    assert!(zig.contains("pub const B = ?*isize;"));
+    // The built-in rule would make this `c_int`.
+    assert!(zig.contains("pub const C = bool;"));
+    assert!(zig.contains("pub const D = WORD;"));
+}
+
+#[test]
+fn malformed() {
+    for source in ["DWORD u32", "DWORD = u32", "DWORD = \"u32", "= \"u32\"", "A = \"a\" b"] {
+        let source = format!("# comment\n{}", source);
+        match parse_type_map(&source) {
+            Err(Error::TypeMap(2, _)) => (),
+            result => panic!("{:?} parsed as {:?}", source, result),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Checking for types that are never declared or imported.
+
+use winapi_zig::{convert_str_with_options, Options, Translation};
+
+fn convert(source: &str) -> Translation {
+    let options = Options {
+        check_undeclared: true,
+        ..Options::default()
+    };
+    convert_str_with_options(source, &options).unwrap()
+}
+
+#[test]
+fn mutually_referential() {
+    let translation = convert(
+        "
+use shared::minwindef::DWORD;
+STRUCT!{struct NODE {
+    size: DWORD,
+    list: *mut LIST,
+}}
+STRUCT!{struct LIST {
+    head: *mut NODE,
+    lists: PLIST,
+}}
+pub type PLIST = *mut LIST;
+pub type Pair<T> = [T; 2];
+",
+    );
+    assert!(translation.diagnostics.is_empty(), "{:?}", translation.diagnostics);
+}
+
+#[test]
+fn missing() {
+    let translation = convert(
+        "
+STRUCT!{struct NODE {
+    size: DWORD,
+    data: *mut DWORD,
+    cb: CALLBACK,
+}}
+pub type CALLBACK = Option<unsafe extern \"system\" fn(x: Missing)>;
+",
+    );
+    let names: Vec<_> = translation.diagnostics.iter().map(|d| d.name.as_deref()).collect();
+    assert_eq!(names, [Some("DWORD"), Some("Missing")]);
+    let d = &translation.diagnostics[0];
+    assert_eq!((d.kind, d.line, d.column), ("type reference", 3, 11));
+}
+
+#[test]
+fn glob_imports() {
+    let translation = convert("use um::winnt::*; pub type A = LONG;");
+    assert!(translation.diagnostics.is_empty());
+}
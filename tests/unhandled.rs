@@ -0,0 +1,55 @@
+//! The summary printed by `--list-unhandled`.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use winapi_zig::convert_str;
+
+#[test]
+fn summary() {
+    let source = r#"
+pub const OK: u32 = 1;
+fn helper() -> u32 {
+    let x = 2;
+    x
+}
+FOO!{x}
+BAR!{y}
+pub type CELL = Cell<u32>;
+extern "system" {
+    pub static GLOBAL: u32;
+}
+"#;
+    let translation = convert_str(source).unwrap();
+    assert_eq!(
+        translation.unhandled_summary(),
+        "fn (1): helper\nforeign item (1): GLOBAL\nmacro (2): FOO!, BAR!\ntype (1): CELL\n"
+    );
+}
+
+#[test]
+fn nothing_unhandled() {
+    let translation = convert_str("pub const OK: u32 = 1;").unwrap();
+    assert_eq!(translation.unhandled_summary(), "");
+}
+
+#[test]
+fn continues_past_a_broken_file() {
+    let dir = env::temp_dir().join("winapi-zig-list-unhandled");
+    fs::create_dir_all(&dir).unwrap();
+    let (broken, good) = (dir.join("broken.rs"), dir.join("good.rs"));
+    fs::write(&broken, "pub const A: u32 = ;\n").unwrap();
+    fs::write(&good, "FOO!{x}\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_winapi-zig"))
+        .arg("--list-unhandled")
+        .args([&broken, &good])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let failure = format!("{}: Unable to parse file", broken.display());
+    assert!(stderr.contains(&failure), "{}", stderr);
+    let summary = format!("{}: 1 item(s) not translated\nmacro (1): FOO!\n", good.display());
+    assert!(stderr.contains(&summary), "{}", stderr);
+}
@@ -0,0 +1,34 @@
+//! Logging each item with `--verbose`.
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn one_line_per_item() {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/struct_attrs.rs");
+    let output = Command::new(env!("CARGO_BIN_EXE_winapi-zig"))
+        .arg("--verbose")
+        .arg(&fixture)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let prefix = format!("{}:", fixture.display());
+    let logged: Vec<_> = stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix(&prefix))
+        .filter(|line| line.contains(": translated ") || line.contains(": skipped "))
+        .collect();
+    assert_eq!(
+        logged,
+        [
+            "1:1: translated macro STRUCT!",
+            "9:1: translated macro STRUCT!",
+            "13:1: skipped macro STRUCT!",
+            "17:11: translated const AFTER",
+        ]
+    );
+    // The Zig output is unchanged.
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains("translated"));
+}